@@ -3,8 +3,20 @@ extern crate pretty_env_logger;
 extern crate termcolor;
 
 use clap::{App, AppSettings, Arg};
-use log::{debug, error};
-use sahih::{config::ConfigManager, printer::Printer, Sahih};
+use log::debug;
+use sahih::{
+    config::ConfigManager,
+    error::SahihError,
+    printer::{PrintHelper, Printer},
+    Sahih,
+};
+
+fn exit_with(error: SahihError) -> ! {
+    let std_error = termcolor::StandardStream::stderr(termcolor::ColorChoice::Always);
+    // Nothing else to report to if stderr itself is broken
+    let _ = Printer::new(std_error).print_error(&error);
+    std::process::exit(error.exit_code());
+}
 
 fn main() {
     pretty_env_logger::init();
@@ -29,7 +41,7 @@ fn main() {
     debug!("{:?}", cli.subcommand());
 
     let config_path = cli.value_of("config").unwrap_or("sahih.config.json");
-    let config_manager = ConfigManager::from(config_path);
+    let config_manager = ConfigManager::from(config_path).unwrap_or_else(|e| exit_with(e));
     let std_output = termcolor::StandardStream::stdout(termcolor::ColorChoice::Always);
     let sahih = Sahih::new(config_manager, Printer::new(std_output));
 
@@ -38,7 +50,7 @@ fn main() {
             Ok(_) => {
                 debug!("Succes");
             }
-            Err(e) => error!("Could not generate validation. \n {:?}", e),
+            Err(e) => exit_with(e),
         },
         _ => unreachable!(),
     }
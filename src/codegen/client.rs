@@ -0,0 +1,774 @@
+use indexmap::IndexSet;
+
+use super::{
+    interface::{is_identifier, serialize_prop_name, serialize_prop_type},
+    model::PropType,
+    operation::{Operation, OperationParam, ParamLocation},
+    validation::{type_rules, ValidatorEmitter},
+};
+
+/// Dates are sent as RFC 3339 strings rather than their `toString()`
+const SERIALIZE_PARAM: &str = r#"function serializeParam(value: unknown): string {
+	return value instanceof Date ? value.toISOString() : String(value);
+}
+"#;
+
+/// `JSON.stringify` throws on `bigint`s, they are sent as strings
+const BIGINT_REPLACER: &str = r#"export function bigintReplacer(_key: string, value: unknown): unknown {
+	return typeof value === "bigint" ? value.toString() : value;
+}
+"#;
+
+const FETCH_RUNTIME: &str = r#"export const clientConfig: { baseUrl: string; init?: RequestInit } = {
+	baseUrl: "",
+};
+
+export class HttpError extends Error {
+	constructor(public readonly response: Response) {
+		super(`Request failed with status ${response.status}`);
+	}
+}
+
+function buildQuery(query: Record<string, unknown>): string {
+	const search = new URLSearchParams();
+	for (const [key, value] of Object.entries(query)) {
+		if (value === undefined || value === null) continue;
+		if (Array.isArray(value)) value.forEach((item) => search.append(key, serializeParam(item)));
+		else search.append(key, serializeParam(value));
+	}
+	const serialized = search.toString();
+	return serialized ? `?${serialized}` : "";
+}
+
+async function request<T>(method: string, url: string, body?: unknown, init?: RequestInit): Promise<T> {
+	const response = await fetch(`${clientConfig.baseUrl}${url}`, {
+		...clientConfig.init,
+		...init,
+		method,
+		headers: {
+			...(body !== undefined ? { "Content-Type": "application/json" } : {}),
+			...(clientConfig.init?.headers as Record<string, string>),
+			...(init?.headers as Record<string, string>),
+		},
+		body: body !== undefined ? JSON.stringify(body, bigintReplacer) : undefined,
+	});
+
+	if (!response.ok) {
+		throw new HttpError(response);
+	}
+
+	const text = await response.text();
+	return (text ? JSON.parse(text) : undefined) as T;
+}
+"#;
+
+const RESPONSE_VALIDATION_ERROR: &str = r#"export class ResponseValidationError extends Error {
+	constructor(
+		public readonly operation: string,
+		public readonly path: string,
+		public readonly reason: string,
+		public readonly value: unknown,
+	) {
+		super(`Invalid response of ${operation} at ${path || "(root)"}: ${reason}`);
+	}
+}
+"#;
+
+const AXIOS_IMPORT: &str = r#"import type { AxiosInstance, AxiosRequestConfig } from "axios";"#;
+
+const AXIOS_CLASS: &str = r#"export class ApiClient {
+	constructor(private readonly axios: AxiosInstance) {}"#;
+
+const AXIOS_FOOTER: &str = r#"}
+
+let defaultClient: ApiClient | undefined;
+
+/** Sets the client the generated hooks call */
+export function setDefaultClient(client: ApiClient): void {
+	defaultClient = client;
+}
+
+export function getDefaultClient(): ApiClient {
+	if (!defaultClient) {
+		throw new Error("No default client, call setDefaultClient first");
+	}
+	return defaultClient;
+}
+"#;
+
+/// HTTP library the client is generated for, picked per project
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum ClientLib {
+    /// Functions calling `fetch`
+    #[default]
+    Fetch,
+    /// `ApiClient` class wrapping an `AxiosInstance`, configured and intercepted by the caller
+    Axios,
+}
+
+fn param_accessor(name: &str) -> String {
+    if is_identifier(name) {
+        format!("params.{}", name)
+    } else {
+        format!("params[\"{}\"]", name)
+    }
+}
+
+pub(super) fn serialize_params_type(params: &[&OperationParam]) -> String {
+    let fields: Vec<(String, &OperationParam)> = params
+        .iter()
+        .map(|param| (param.name.clone(), *param))
+        .collect();
+
+    serialize_fields(&fields)
+}
+
+/// Object type of params keyed by the given names
+fn serialize_fields(fields: &[(String, &OperationParam)]) -> String {
+    let fields: Vec<String> = fields
+        .iter()
+        .map(|(name, param)| {
+            format!(
+                "{}{optional_op}: {}",
+                serialize_prop_name(name),
+                serialize_prop_type(&param.prop_type),
+                optional_op = if param.required { "" } else { "?" }
+            )
+        })
+        .collect();
+
+    format!("{{ {} }}", fields.join("; "))
+}
+
+/// Turns an OpenAPI path template into a JS template literal body
+fn serialize_path(path: &str) -> String {
+    let mut serialized = String::new();
+    let mut rest = path;
+
+    while let Some(start) = rest.find('{') {
+        let end = match rest[start..].find('}') {
+            Some(end) => start + end,
+            None => break,
+        };
+
+        serialized.push_str(&rest[..start]);
+        serialized.push_str(&format!(
+            "${{encodeURIComponent(serializeParam({}))}}",
+            param_accessor(&rest[start + 1..end])
+        ));
+        rest = &rest[end + 1..];
+    }
+
+    serialized.push_str(rest);
+    serialized
+}
+
+/// Query params keyed by their name in the spec, read from their client argument
+fn serialize_query_fields(operation: &Operation) -> Vec<String> {
+    operation
+        .params_in(ParamLocation::Query)
+        .map(|param| {
+            format!(
+                "{}: {}",
+                serialize_prop_name(&param.name),
+                param_accessor(&operation.arg_name(param))
+            )
+        })
+        .collect()
+}
+
+fn serialize_query(operation: &Operation) -> String {
+    let fields = serialize_query_fields(operation);
+    if fields.is_empty() {
+        return String::new();
+    }
+
+    format!("${{buildQuery({{ {} }})}}", fields.join(", "))
+}
+
+/// Type imports of the models the operations reference
+pub(super) fn serialize_imports(operations: &[Operation]) -> String {
+    let models: IndexSet<&str> = operations
+        .iter()
+        .flat_map(|operation| operation.references())
+        .collect();
+
+    if models.is_empty() {
+        String::new()
+    } else {
+        format!(
+            "import type {{ {} }} from \"./models\";\n\n",
+            models.into_iter().collect::<Vec<&str>>().join(", ")
+        )
+    }
+}
+
+/// Response with the models holding `writeOnly` properties swapped for their `Output` variant,
+/// which leaves out the properties servers never send back
+fn validated_response(response: &PropType, outputs: Option<&IndexSet<String>>) -> PropType {
+    let mut response = response.clone();
+    for model_ref in response.references_mut() {
+        if outputs.is_some_and(|outputs| outputs.contains(&model_ref.name)) {
+            model_ref.name.push_str("Output");
+        }
+    }
+
+    response
+}
+
+pub struct ClientGenerator<'a> {
+    operation: &'a Operation,
+    pub lib: ClientLib,
+    /// Emitter of the validators responses are checked with, responses are trusted when unset
+    pub validator: Option<&'a dyn ValidatorEmitter>,
+    /// Whether `int64`s are typed as `bigint`s, axios can't serialize bodies holding them
+    pub bigints: bool,
+    /// Names of the models with an `Output` variant, responses are validated against the variant
+    pub outputs: Option<&'a IndexSet<String>>,
+}
+
+impl<'a> ClientGenerator<'a> {
+    pub fn from(operation: &'a Operation) -> Self {
+        Self {
+            operation,
+            lib: ClientLib::default(),
+            validator: None,
+            bigints: false,
+            outputs: None,
+        }
+    }
+
+    /// Imports of the referenced models followed by the shared runtime of the library,
+    /// and the response validation helpers when `validator` is set
+    pub fn header(
+        operations: &[Operation],
+        lib: ClientLib,
+        validator: Option<&dyn ValidatorEmitter>,
+        bigints: bool,
+        outputs: Option<&IndexSet<String>>,
+    ) -> String {
+        let mut imports: Vec<String> = vec![];
+        let mut runtime: Vec<String> = vec![];
+        if lib == ClientLib::Axios {
+            imports.push(AXIOS_IMPORT.to_string());
+        }
+        imports.push(serialize_imports(operations).trim_end().to_string());
+        // Axios serializes the query itself, only path params need the helper
+        let has_path_params = operations
+            .iter()
+            .any(|operation| operation.params_in(ParamLocation::Path).next().is_some());
+        if lib == ClientLib::Fetch || has_path_params {
+            runtime.push(SERIALIZE_PARAM.to_string());
+        }
+        // The fetch runtime always serializes bodies with the replacer
+        if lib == ClientLib::Fetch || bigints {
+            runtime.push(BIGINT_REPLACER.to_string());
+        }
+        if lib == ClientLib::Fetch {
+            runtime.push(FETCH_RUNTIME.to_string());
+        }
+
+        // The helpers are only emitted when some operation has a response to check
+        let validation = validator
+            .filter(|_| {
+                operations
+                    .iter()
+                    .any(|operation| operation.response.is_some())
+            })
+            .and_then(|emitter| Some((emitter, emitter.response_validation()?)));
+        if let Some((emitter, response_validation)) = validation {
+            let validators: IndexSet<String> = operations
+                .iter()
+                .flat_map(|operation| operation.response.iter())
+                .map(|response| validated_response(response, outputs))
+                .flat_map(|response| {
+                    (response.references().into_iter())
+                        .map(|model_ref| format!("{}Validator", model_ref.name))
+                        .collect::<Vec<String>>()
+                })
+                .collect();
+
+            imports.push(emitter.import());
+            if !validators.is_empty() {
+                imports.push(format!(
+                    "import {{ {} }} from \"./models\";",
+                    validators.into_iter().collect::<Vec<String>>().join(", ")
+                ));
+            }
+            runtime.push(RESPONSE_VALIDATION_ERROR.to_string());
+            runtime.push(response_validation);
+        }
+
+        imports.retain(|import| !import.is_empty());
+        let imports = if imports.is_empty() {
+            String::new()
+        } else {
+            format!("{}\n\n", imports.join("\n"))
+        };
+        let runtime = runtime.join("\n");
+
+        match lib {
+            ClientLib::Fetch => format!("// Generated with Sahih\n{}{}", imports, runtime),
+            ClientLib::Axios if runtime.is_empty() => {
+                format!("// Generated with Sahih\n{}{}", imports, AXIOS_CLASS)
+            }
+            ClientLib::Axios => format!(
+                "// Generated with Sahih\n{}{}\n{}",
+                imports, runtime, AXIOS_CLASS
+            ),
+        }
+    }
+
+    /// Closes what the header opened
+    pub fn footer(lib: ClientLib) -> &'static str {
+        match lib {
+            ClientLib::Fetch => "",
+            ClientLib::Axios => AXIOS_FOOTER,
+        }
+    }
+
+    pub fn build(&self) -> String {
+        match self.lib {
+            ClientLib::Fetch => self.build_fetch(),
+            ClientLib::Axios => self.build_axios(),
+        }
+    }
+
+    /// Arguments of the operation, followed by the request options of the library
+    fn build_args(&self, options: &str) -> String {
+        let operation = self.operation;
+        let params: Vec<(String, &OperationParam)> = operation
+            .params_in(ParamLocation::Path)
+            .chain(operation.params_in(ParamLocation::Query))
+            .map(|param| (operation.arg_name(param), param))
+            .collect();
+
+        let mut args: Vec<String> = vec![];
+        if !params.is_empty() {
+            args.push(format!("params: {}", serialize_fields(&params)));
+        }
+        if let Some(body) = &operation.body {
+            args.push(format!(
+                "body{}: {}",
+                if body.required { "" } else { "?" },
+                serialize_prop_type(&body.prop_type)
+            ));
+        }
+        args.push(options.to_string());
+
+        args.join(", ")
+    }
+
+    fn response_type(&self) -> String {
+        match &self.operation.response {
+            Some(response) => serialize_prop_type(response),
+            None => String::from("void"),
+        }
+    }
+
+    /// Checks `data` against the validator of the response, when there is one to check
+    fn validate(&self, data: &str) -> Option<String> {
+        let emitter = self.validator?;
+        emitter.response_validation()?;
+        let response = validated_response(self.operation.response.as_ref()?, self.outputs);
+        // The schemas of the operations aren't reported, only the models are
+        let rules = type_rules(&response, "", &mut vec![]);
+
+        Some(format!(
+            "validateResponse<{}>(\"{}\", {}, {})",
+            self.response_type(),
+            self.operation.name,
+            emitter.schema(&rules),
+            data
+        ))
+    }
+
+    /// Method of the `ApiClient` class, axios serializes the query params itself
+    fn build_axios(&self) -> String {
+        let operation = self.operation;
+        let query = serialize_query_fields(operation);
+
+        let mut request = vec![
+            String::from("...config"),
+            format!("method: \"{}\"", operation.method.to_uppercase()),
+            format!("url: `{}`", serialize_path(&operation.path)),
+        ];
+        if !query.is_empty() {
+            request.push(format!("params: {{ {} }}", query.join(", ")));
+        }
+        if operation.body.is_some() && self.bigints {
+            // Axios stringifies the data without a replacer, the bigints are converted beforehand
+            request.push(String::from(
+                "data: JSON.parse(JSON.stringify(body, bigintReplacer))",
+            ));
+        } else if operation.body.is_some() {
+            request.push(String::from("data: body"));
+        }
+
+        let (data_type, data) = match self.validate("response.data") {
+            Some(validated) => (String::from("unknown"), validated),
+            None => (self.response_type(), String::from("response.data")),
+        };
+
+        format!(
+            "\n\t{name}({args}): Promise<{response}> {{\n\t\treturn this.axios\n\t\t\t.request<{data_type}>({{ {request} }})\n\t\t\t.then((response) => {data});\n\t}}",
+            name = operation.name,
+            args = self.build_args("config?: AxiosRequestConfig"),
+            response = self.response_type(),
+            data_type = data_type,
+            request = request.join(", "),
+            data = data,
+        )
+    }
+
+    fn build_fetch(&self) -> String {
+        let operation = self.operation;
+        let (data_type, validation) = match self.validate("data") {
+            Some(validated) => (
+                String::from("unknown"),
+                format!(".then((data) => {})", validated),
+            ),
+            None => (self.response_type(), String::new()),
+        };
+
+        format!(
+            "export function {name}({args}): Promise<{response}> {{\n\treturn request<{data_type}>(\"{method}\", `{path}{query}`, {body}, init){validation};\n}}\n",
+            name = operation.name,
+            args = self.build_args("init?: RequestInit"),
+            response = self.response_type(),
+            data_type = data_type,
+            validation = validation,
+            method = operation.method.to_uppercase(),
+            path = serialize_path(&operation.path),
+            query = serialize_query(operation),
+            body = if operation.body.is_some() { "body" } else { "undefined" },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indexmap::IndexSet;
+    use openapiv3::{StringFormat, StringType, Type, VariantOrUnknownOrEmpty};
+
+    use crate::codegen::{
+        client::serialize_path,
+        model::{ArrayProp, ModelRef, PropType},
+        operation::{Operation, OperationBody, OperationParam, ParamLocation},
+        validation::{ArrayRules, NumberRules, PropRules, PropValidation, StringRules},
+        ClientGenerator, ClientLib, ValidationGenerator, ValidatorEmitter, Zod,
+    };
+
+    #[test]
+    fn it_interpolates_path_params() {
+        assert_eq!(
+            serialize_path("/rooms/{roomId}/bids/{bid-id}"),
+            r#"/rooms/${encodeURIComponent(serializeParam(params.roomId))}/bids/${encodeURIComponent(serializeParam(params["bid-id"]))}"#
+        );
+    }
+
+    #[test]
+    fn it_builds_query_operation() {
+        let operation = Operation {
+            name: "AuctionController_getAuction".to_string(),
+            method: "get".to_string(),
+            path: "/auction/house".to_string(),
+            parameters: vec![OperationParam {
+                name: "houseId".to_string(),
+                location: ParamLocation::Query,
+                required: true,
+                prop_type: PropType::Type(Type::String(StringType::default())),
+            }],
+            body: None,
+            response: Some(PropType::Reference(ModelRef::new("AuctionRoomDTO"))),
+            responses: vec![],
+        };
+
+        assert_eq!(
+            ClientGenerator::from(&operation).build(),
+            r#"export function AuctionController_getAuction(params: { houseId: string }, init?: RequestInit): Promise<AuctionRoomDTO> {
+	return request<AuctionRoomDTO>("GET", `/auction/house${buildQuery({ houseId: params.houseId })}`, undefined, init);
+}
+"#
+        );
+    }
+
+    #[test]
+    fn it_renames_clashing_params() {
+        let param = |location| OperationParam {
+            name: "id".to_string(),
+            location,
+            required: true,
+            prop_type: PropType::Type(Type::String(StringType::default())),
+        };
+        let operation = Operation {
+            name: "AuctionController_getAuction".to_string(),
+            method: "get".to_string(),
+            path: "/auction/{id}".to_string(),
+            parameters: vec![param(ParamLocation::Path), param(ParamLocation::Query)],
+            body: None,
+            response: None,
+            responses: vec![],
+        };
+
+        assert_eq!(
+            ClientGenerator::from(&operation).build(),
+            r#"export function AuctionController_getAuction(params: { id: string; idQuery: string }, init?: RequestInit): Promise<void> {
+	return request<void>("GET", `/auction/${encodeURIComponent(serializeParam(params.id))}${buildQuery({ id: params.idQuery })}`, undefined, init);
+}
+"#
+        );
+    }
+
+    #[test]
+    fn it_builds_body_operation() {
+        let operation = Operation {
+            name: "BidController_putBid".to_string(),
+            method: "post".to_string(),
+            path: "/bid".to_string(),
+            parameters: vec![],
+            body: Some(OperationBody {
+                required: true,
+                prop_type: PropType::Reference(ModelRef::new("PlacingBidInput")),
+            }),
+            response: None,
+            responses: vec![],
+        };
+
+        assert_eq!(
+            ClientGenerator::from(&operation).build(),
+            r#"export function BidController_putBid(body: PlacingBidInput, init?: RequestInit): Promise<void> {
+	return request<void>("POST", `/bid`, body, init);
+}
+"#
+        );
+    }
+
+    #[test]
+    fn it_converts_bigint_bodies() {
+        let operation = Operation {
+            name: "BidController_putBid".to_string(),
+            method: "post".to_string(),
+            path: "/bid".to_string(),
+            parameters: vec![],
+            body: Some(OperationBody {
+                required: true,
+                prop_type: PropType::BigInt,
+            }),
+            response: None,
+            responses: vec![],
+        };
+        let mut generator = ClientGenerator::from(&operation);
+        generator.lib = ClientLib::Axios;
+        generator.bigints = true;
+
+        assert!(generator
+            .build()
+            .contains("data: JSON.parse(JSON.stringify(body, bigintReplacer))"));
+        assert!(
+            ClientGenerator::header(&[operation], ClientLib::Axios, None, true, None)
+                .contains("export function bigintReplacer(")
+        );
+        assert!(
+            ClientGenerator::header(&[], ClientLib::Fetch, None, false, None)
+                .contains("JSON.stringify(body, bigintReplacer)")
+        );
+    }
+
+    #[test]
+    fn it_builds_axios_method() {
+        let operation = Operation {
+            name: "AuctionController_getAuction".to_string(),
+            method: "get".to_string(),
+            path: "/auction/{houseId}".to_string(),
+            parameters: vec![
+                OperationParam {
+                    name: "houseId".to_string(),
+                    location: ParamLocation::Path,
+                    required: true,
+                    prop_type: PropType::Type(Type::String(StringType::default())),
+                },
+                OperationParam {
+                    name: "page-size".to_string(),
+                    location: ParamLocation::Query,
+                    required: false,
+                    prop_type: PropType::Type(Type::String(StringType::default())),
+                },
+            ],
+            body: None,
+            response: Some(PropType::Reference(ModelRef::new("AuctionRoomDTO"))),
+            responses: vec![],
+        };
+        let mut generator = ClientGenerator::from(&operation);
+        generator.lib = ClientLib::Axios;
+
+        assert_eq!(
+            generator.build(),
+            r#"
+	AuctionController_getAuction(params: { houseId: string; "page-size"?: string }, config?: AxiosRequestConfig): Promise<AuctionRoomDTO> {
+		return this.axios
+			.request<AuctionRoomDTO>({ ...config, method: "GET", url: `/auction/${encodeURIComponent(serializeParam(params.houseId))}`, params: { "page-size": params["page-size"] } })
+			.then((response) => response.data);
+	}"#
+        );
+        assert!(
+            ClientGenerator::header(&[operation], ClientLib::Axios, None, false, None)
+                .contains("function serializeParam(value: unknown): string")
+        );
+    }
+
+    #[test]
+    fn it_validates_responses() {
+        let operation = Operation {
+            name: "AuctionController_listAuctions".to_string(),
+            method: "get".to_string(),
+            path: "/auction".to_string(),
+            parameters: vec![],
+            body: None,
+            response: Some(PropType::Array(ArrayProp {
+                items: Box::new(PropType::Reference(ModelRef::new("AuctionRoomDTO"))),
+                min_items: None,
+                max_items: None,
+                unique_items: false,
+            })),
+            responses: vec![],
+        };
+        let mut generator = ClientGenerator::from(&operation);
+        generator.validator = Some(&Zod);
+
+        assert_eq!(
+            generator.build(),
+            r#"export function AuctionController_listAuctions(init?: RequestInit): Promise<AuctionRoomDTO[]> {
+	return request<unknown>("GET", `/auction`, undefined, init).then((data) => validateResponse<AuctionRoomDTO[]>("AuctionController_listAuctions", z.array(AuctionRoomDTOValidator), data));
+}
+"#
+        );
+
+        let header =
+            ClientGenerator::header(&[operation], ClientLib::Fetch, Some(&Zod), false, None);
+        assert!(header.contains(
+            "import { z } from \"zod\";\nimport { AuctionRoomDTOValidator } from \"./models\";"
+        ));
+        assert!(header.contains("export class ResponseValidationError extends Error"));
+        assert!(header.contains("function validateResponse<T>("));
+    }
+
+    #[test]
+    fn it_validates_responses_against_output_variants() {
+        let operation = Operation {
+            name: "getUser".to_string(),
+            method: "get".to_string(),
+            path: "/user".to_string(),
+            parameters: vec![],
+            body: None,
+            response: Some(PropType::Reference(ModelRef::new("User"))),
+            responses: vec![],
+        };
+        let outputs: IndexSet<String> = [String::from("User")].into_iter().collect();
+        let mut generator = ClientGenerator::from(&operation);
+        generator.validator = Some(&Zod);
+        generator.outputs = Some(&outputs);
+
+        assert!(generator
+            .build()
+            .contains(r#"validateResponse<User>("getUser", UserOutputValidator, data)"#));
+        let header = ClientGenerator::header(
+            &[operation],
+            ClientLib::Fetch,
+            Some(&Zod),
+            false,
+            Some(&outputs),
+        );
+        assert!(header.contains("import { UserOutputValidator } from \"./models\";"));
+    }
+
+    #[test]
+    fn it_skips_validation_helpers_without_responses() {
+        let header = ClientGenerator::header(&[], ClientLib::Fetch, Some(&Zod), false, None);
+
+        assert!(!header.contains("import { z }"));
+        assert!(!header.contains("validateResponse"));
+    }
+
+    #[test]
+    fn it_converts_date_responses() {
+        let mut operation = Operation {
+            name: "AuctionController_getEnd".to_string(),
+            method: "get".to_string(),
+            path: "/auction/end".to_string(),
+            parameters: vec![],
+            body: None,
+            response: Some(PropType::Type(Type::String(StringType {
+                format: VariantOrUnknownOrEmpty::Item(StringFormat::DateTime),
+                ..Default::default()
+            }))),
+            responses: vec![],
+        };
+        operation.use_date_objects();
+        let mut generator = ClientGenerator::from(&operation);
+        generator.validator = Some(&Zod);
+
+        assert!(generator.build().contains(
+            r#".then((data) => validateResponse<Date>("AuctionController_getEnd", z.coerce.date(), data));"#
+        ));
+    }
+
+    /// Third-party emitter which can't validate responses
+    struct Unchecked;
+
+    impl ValidatorEmitter for Unchecked {
+        fn import(&self) -> String {
+            Zod.import()
+        }
+
+        fn number_rule(&self, rule: &NumberRules) -> String {
+            Zod.number_rule(rule)
+        }
+
+        fn string_rule(&self, rule: &StringRules) -> String {
+            Zod.string_rule(rule)
+        }
+
+        fn array_rule(&self, rule: &ArrayRules) -> String {
+            Zod.array_rule(rule)
+        }
+
+        fn schema(&self, rules: &PropRules) -> String {
+            Zod.schema(rules)
+        }
+
+        fn property(&self, validation: &PropValidation) -> String {
+            Zod.property(validation)
+        }
+
+        fn validator(&self, generator: &ValidationGenerator) -> String {
+            Zod.validator(generator)
+        }
+    }
+
+    #[test]
+    fn it_returns_responses_unchecked_without_helper() {
+        let operation = Operation {
+            name: "AuctionController_getAuction".to_string(),
+            method: "get".to_string(),
+            path: "/auction".to_string(),
+            parameters: vec![],
+            body: None,
+            response: Some(PropType::Reference(ModelRef::new("AuctionRoomDTO"))),
+            responses: vec![],
+        };
+        let mut generator = ClientGenerator::from(&operation);
+        generator.validator = Some(&Unchecked);
+
+        assert!(!generator.build().contains("validateResponse"));
+        let header = ClientGenerator::header(
+            &[operation],
+            ClientLib::Fetch,
+            Some(&Unchecked),
+            false,
+            None,
+        );
+        assert!(!header.contains("validateResponse"));
+    }
+}
@@ -0,0 +1,507 @@
+use indexmap::IndexSet;
+
+use super::{
+    interface::{is_identifier, serialize_prop_name, serialize_prop_type},
+    operation::{Operation, OperationParam, ParamLocation},
+    ClientLib, OperationTypesGenerator,
+};
+
+/// Data fetching library the hooks are generated for, picked per project
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum HookLib {
+    #[serde(rename = "react-query")]
+    ReactQuery,
+    #[serde(rename = "swr")]
+    Swr,
+}
+
+impl HookLib {
+    /// Imports of the query and mutation hooks, only the used ones are imported
+    fn import(&self, queries: bool, mutations: bool) -> String {
+        match self {
+            HookLib::ReactQuery => {
+                let mut symbols: Vec<&str> = vec![];
+                if mutations {
+                    symbols.push("useMutation");
+                }
+                if queries {
+                    symbols.push("useQuery");
+                }
+                if mutations {
+                    symbols.push("type UseMutationOptions");
+                }
+                if queries {
+                    symbols.push("type UseQueryOptions");
+                }
+                format!(
+                    "import {{ {} }} from \"@tanstack/react-query\";",
+                    symbols.join(", ")
+                )
+            }
+            HookLib::Swr => {
+                let mut imports: Vec<&str> = vec![];
+                if queries {
+                    imports.push("import useSWR, { type SWRConfiguration } from \"swr\";");
+                }
+                if mutations {
+                    imports.push("import useSWRMutation, { type SWRMutationConfiguration } from \"swr/mutation\";");
+                }
+                imports.join("\n")
+            }
+        }
+    }
+}
+
+/// Whether the operation gets a query hook rather than a mutation one, `None` for HEAD, OPTIONS
+/// and TRACE operations which get neither
+fn is_query(operation: &Operation) -> Option<bool> {
+    match operation.method.as_str() {
+        "get" => Some(true),
+        "post" | "put" | "patch" | "delete" => Some(false),
+        _ => None,
+    }
+}
+
+/// `useXxxQuery` hook of a GET operation, or `useXxxMutation` hook of a POST, PUT, PATCH or DELETE one
+#[derive(Debug)]
+pub struct HooksGenerator<'a> {
+    operation: &'a Operation,
+    lib: HookLib,
+    /// Flavour of the client the hooks call
+    pub client: ClientLib,
+    /// Whether `int64`s are typed as `bigint`s, which the query key hashing can't serialize
+    pub bigints: bool,
+}
+
+impl<'a> HooksGenerator<'a> {
+    pub fn from(operation: &'a Operation, lib: HookLib) -> Self {
+        Self {
+            operation,
+            lib,
+            client: ClientLib::default(),
+            bigints: false,
+        }
+    }
+
+    /// Imports of the hooks library, the models of the responses, the operation types and the client,
+    /// `operations` being the ones hooks are built for
+    pub fn header(operations: &[&Operation], lib: HookLib) -> String {
+        let models: IndexSet<&str> = operations
+            .iter()
+            .flat_map(|operation| operation.response.iter())
+            .flat_map(|response| response.references())
+            .map(|model_ref| model_ref.name.as_str())
+            .collect();
+
+        let queries = operations
+            .iter()
+            .any(|operation| is_query(operation) == Some(true));
+        let mutations = operations
+            .iter()
+            .any(|operation| is_query(operation) == Some(false));
+
+        let mut imports = vec![lib.import(queries, mutations)];
+        if !models.is_empty() {
+            imports.push(format!(
+                "import type {{ {} }} from \"./models\";",
+                models.into_iter().collect::<Vec<&str>>().join(", ")
+            ));
+        }
+        // Operation types only name the params and bodies
+        if operations
+            .iter()
+            .any(|operation| !operation.parameters.is_empty() || operation.body.is_some())
+        {
+            imports.push(String::from(
+                "import type * as operations from \"./operations\";",
+            ));
+        }
+        imports.push(String::from("import * as client from \"./client\";"));
+
+        format!("// Generated with Sahih\n{}\n", imports.join("\n"))
+    }
+
+    fn type_name(&self) -> String {
+        OperationTypesGenerator::from(self.operation).type_name()
+    }
+
+    fn response_type(&self) -> String {
+        match &self.operation.response {
+            Some(response) => serialize_prop_type(response),
+            None => String::from("void"),
+        }
+    }
+
+    /// Call of the client function, taking its arguments from `params` and `body`
+    fn client_call(&self, init: &str) -> String {
+        let operation = self.operation;
+        let mut args: Vec<String> = vec![];
+
+        let mut fields: Vec<String> = vec![];
+        if operation.params_in(ParamLocation::Path).next().is_some() {
+            fields.push(String::from("...params.path"));
+        }
+        let query: Vec<&OperationParam> = operation.params_in(ParamLocation::Query).collect();
+        if query
+            .iter()
+            .all(|param| operation.arg_name(param) == param.name)
+        {
+            if !query.is_empty() {
+                fields.push(String::from("...params.query"));
+            }
+        } else {
+            // Renamed query params can't be spread next to the path params they clash with
+            fields.extend(query.iter().map(|param| {
+                let accessor = if is_identifier(&param.name) {
+                    format!("params.query?.{}", param.name)
+                } else {
+                    format!("params.query?.[\"{}\"]", param.name)
+                };
+                format!(
+                    "{}: {}",
+                    serialize_prop_name(&operation.arg_name(param)),
+                    accessor
+                )
+            }));
+        }
+        if !fields.is_empty() {
+            args.push(format!("{{ {} }}", fields.join(", ")));
+        }
+        if operation.body.is_some() {
+            args.push(String::from("body"));
+        }
+
+        let mut init_fields: Vec<&str> = vec![];
+        if operation.params_in(ParamLocation::Header).next().is_some() {
+            init_fields.push("headers: params.header as Record<string, string>");
+        }
+        if !init.is_empty() {
+            init_fields.push(init);
+        }
+        // The client only takes the arguments the operation has, `init` always comes last
+        if !init_fields.is_empty() {
+            args.push(format!("{{ {} }}", init_fields.join(", ")));
+        }
+
+        let function = match self.client {
+            ClientLib::Fetch => format!("client.{}", operation.name),
+            ClientLib::Axios => format!("client.getDefaultClient().{}", operation.name),
+        };
+        format!("{}({})", function, args.join(", "))
+    }
+
+    /// Query key of the operation, the path template followed by the params
+    fn build_query_key(&self) -> String {
+        let name = self.type_name();
+        let has_params = !self.operation.parameters.is_empty();
+
+        format!(
+            "export const get{name}QueryKey = ({params}) => [{path}{key_params}] as const;",
+            name = name,
+            params = if has_params {
+                format!("params: operations.{}Params", name)
+            } else {
+                String::new()
+            },
+            path = serde_json::to_string(&self.operation.path).unwrap(),
+            key_params = match (has_params, self.bigints) {
+                (true, true) => ", JSON.parse(JSON.stringify(params, client.bigintReplacer))",
+                (true, false) => ", params",
+                (false, _) => "",
+            },
+        )
+    }
+
+    fn build_query(&self) -> String {
+        let name = self.type_name();
+        let has_params = !self.operation.parameters.is_empty();
+        let response = self.response_type();
+
+        format!(
+            "{key}\n\nexport function use{name}Query({params}options?: Omit<UseQueryOptions<{response}>, \"queryKey\" | \"queryFn\">) {{\n\treturn useQuery({{\n\t\tqueryKey: get{name}QueryKey({key_params}),\n\t\tqueryFn: ({{ signal }}) => {call},\n\t\t...options,\n\t}});\n}}\n",
+            key = self.build_query_key(),
+            name = name,
+            params = if has_params {
+                format!("params: operations.{}Params, ", name)
+            } else {
+                String::new()
+            },
+            response = response,
+            key_params = if has_params { "params" } else { "" },
+            call = self.client_call("signal"),
+        )
+    }
+
+    fn build_swr(&self) -> String {
+        let name = self.type_name();
+        let has_params = !self.operation.parameters.is_empty();
+        let response = self.response_type();
+
+        format!(
+            "{key}\n\nexport function use{name}Query({params}config?: SWRConfiguration<{response}>) {{\n\treturn useSWR(get{name}QueryKey({key_params}), () => {call}, config);\n}}\n",
+            key = self.build_query_key(),
+            name = name,
+            params = if has_params {
+                format!("params: operations.{}Params, ", name)
+            } else {
+                String::new()
+            },
+            response = response,
+            key_params = if has_params { "params" } else { "" },
+            call = self.client_call(""),
+        )
+    }
+
+    fn build_swr_mutation(&self) -> String {
+        let name = self.type_name();
+        let (variables, destructured) = self.mutation_variables();
+        // Operations without params nor body are triggered without an argument
+        let (variables, fetcher_args) = match variables {
+            Some(variables) => (
+                variables.clone(),
+                format!(
+                    "_key: string, {{ arg: {} }}: {{ arg: {} }}",
+                    destructured, variables
+                ),
+            ),
+            None => (String::from("never"), String::new()),
+        };
+
+        format!(
+            "export function use{name}Mutation(config?: SWRMutationConfiguration<{response}, Error, string, {variables}>) {{\n\treturn useSWRMutation({key}, ({fetcher_args}) => {call}, config);\n}}\n",
+            name = name,
+            response = self.response_type(),
+            variables = variables,
+            key = serde_json::to_string(&format!(
+                "{} {}",
+                self.operation.method.to_uppercase(),
+                self.operation.path
+            ))
+            .unwrap(),
+            fetcher_args = fetcher_args,
+            call = self.client_call(""),
+        )
+    }
+
+    /// Type of the `params` and `body` the mutation is called with and their destructuring,
+    /// `None` when the operation takes neither
+    fn mutation_variables(&self) -> (Option<String>, String) {
+        let operation = self.operation;
+        let name = self.type_name();
+
+        let mut variables: Vec<String> = vec![];
+        let mut destructured: Vec<&str> = vec![];
+        if !operation.parameters.is_empty() {
+            variables.push(format!("params: operations.{}Params", name));
+            destructured.push("params");
+        }
+        if let Some(body) = &operation.body {
+            variables.push(format!(
+                "body{}: operations.{}Body",
+                if body.required { "" } else { "?" },
+                name
+            ));
+            destructured.push("body");
+        }
+        if variables.is_empty() {
+            (None, String::new())
+        } else {
+            (
+                Some(format!("{{ {} }}", variables.join("; "))),
+                format!("{{ {} }}", destructured.join(", ")),
+            )
+        }
+    }
+
+    fn build_mutation(&self) -> String {
+        let name = self.type_name();
+        let (variables, destructured) = self.mutation_variables();
+        let variables = variables.unwrap_or_else(|| String::from("void"));
+
+        format!(
+            "export function use{name}Mutation(options?: Omit<UseMutationOptions<{response}, Error, {variables}>, \"mutationFn\">) {{\n\treturn useMutation({{\n\t\tmutationFn: ({destructured}) => {call},\n\t\t...options,\n\t}});\n}}\n",
+            name = name,
+            response = self.response_type(),
+            variables = variables,
+            destructured = destructured,
+            call = self.client_call(""),
+        )
+    }
+
+    /// Hook of the operation, `None` for HEAD, OPTIONS and TRACE operations
+    pub fn build(&self) -> Option<String> {
+        let is_query = is_query(self.operation)?;
+
+        Some(match (self.lib, is_query) {
+            (HookLib::ReactQuery, true) => self.build_query(),
+            (HookLib::ReactQuery, false) => self.build_mutation(),
+            (HookLib::Swr, true) => self.build_swr(),
+            (HookLib::Swr, false) => self.build_swr_mutation(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use openapiv3::{StringType, Type};
+
+    use crate::codegen::{
+        model::{ModelRef, PropType},
+        operation::{Operation, OperationBody, OperationParam, ParamLocation},
+        HookLib, HooksGenerator,
+    };
+
+    fn operation(method: &str, parameters: Vec<OperationParam>) -> Operation {
+        Operation {
+            name: "listBids".to_string(),
+            method: method.to_string(),
+            path: "/rooms/{roomId}/bids".to_string(),
+            parameters,
+            body: None,
+            response: Some(PropType::Reference(ModelRef::new("BidDto"))),
+            responses: vec![],
+        }
+    }
+
+    fn param(name: &str, location: ParamLocation) -> OperationParam {
+        OperationParam {
+            name: name.to_string(),
+            location,
+            required: true,
+            prop_type: PropType::Type(Type::String(StringType::default())),
+        }
+    }
+
+    #[test]
+    fn it_builds_react_query_hooks() {
+        let query = operation(
+            "get",
+            vec![
+                param("roomId", ParamLocation::Path),
+                param("x-trace", ParamLocation::Header),
+            ],
+        );
+
+        assert_eq!(
+            HooksGenerator::from(&query, HookLib::ReactQuery)
+                .build()
+                .unwrap(),
+            r#"export const getListBidsQueryKey = (params: operations.ListBidsParams) => ["/rooms/{roomId}/bids", params] as const;
+
+export function useListBidsQuery(params: operations.ListBidsParams, options?: Omit<UseQueryOptions<BidDto>, "queryKey" | "queryFn">) {
+	return useQuery({
+		queryKey: getListBidsQueryKey(params),
+		queryFn: ({ signal }) => client.listBids({ ...params.path }, { headers: params.header as Record<string, string>, signal }),
+		...options,
+	});
+}
+"#
+        );
+    }
+
+    #[test]
+    fn it_builds_react_query_mutations() {
+        let mut mutation = operation("post", vec![param("roomId", ParamLocation::Path)]);
+        mutation.body = Some(OperationBody {
+            required: true,
+            prop_type: PropType::Reference(ModelRef::new("PlacingBidInput")),
+        });
+
+        assert_eq!(
+            HooksGenerator::from(&mutation, HookLib::ReactQuery)
+                .build()
+                .unwrap(),
+            r#"export function useListBidsMutation(options?: Omit<UseMutationOptions<BidDto, Error, { params: operations.ListBidsParams; body: operations.ListBidsBody }>, "mutationFn">) {
+	return useMutation({
+		mutationFn: ({ params, body }) => client.listBids({ ...params.path }, body),
+		...options,
+	});
+}
+"#
+        );
+
+        let head = operation("head", vec![]);
+        assert!(HooksGenerator::from(&head, HookLib::ReactQuery)
+            .build()
+            .is_none());
+    }
+
+    #[test]
+    fn it_builds_swr_hooks() {
+        let query = operation("get", vec![]);
+        assert_eq!(
+            HooksGenerator::from(&query, HookLib::Swr).build().unwrap(),
+            r#"export const getListBidsQueryKey = () => ["/rooms/{roomId}/bids"] as const;
+
+export function useListBidsQuery(config?: SWRConfiguration<BidDto>) {
+	return useSWR(getListBidsQueryKey(), () => client.listBids(), config);
+}
+"#
+        );
+
+        let mutation = operation("delete", vec![param("roomId", ParamLocation::Path)]);
+        assert_eq!(
+            HooksGenerator::from(&mutation, HookLib::Swr)
+                .build()
+                .unwrap(),
+            r#"export function useListBidsMutation(config?: SWRMutationConfiguration<BidDto, Error, string, { params: operations.ListBidsParams }>) {
+	return useSWRMutation("DELETE /rooms/{roomId}/bids", (_key: string, { arg: { params } }: { arg: { params: operations.ListBidsParams } }) => client.listBids({ ...params.path }), config);
+}
+"#
+        );
+    }
+
+    #[test]
+    fn it_stringifies_bigint_query_keys() {
+        let mut query = operation("get", vec![param("roomId", ParamLocation::Path)]);
+        query.parameters[0].prop_type = PropType::BigInt;
+        let mut generator = HooksGenerator::from(&query, HookLib::ReactQuery);
+        generator.bigints = true;
+
+        assert!(generator.build().unwrap().starts_with(
+            r#"export const getListBidsQueryKey = (params: operations.ListBidsParams) => ["/rooms/{roomId}/bids", JSON.parse(JSON.stringify(params, client.bigintReplacer))] as const;"#
+        ));
+    }
+
+    #[test]
+    fn it_imports_used_symbols() {
+        let query = operation("get", vec![]);
+        assert_eq!(
+            HooksGenerator::header(&[&query], HookLib::ReactQuery),
+            r#"// Generated with Sahih
+import { useQuery, type UseQueryOptions } from "@tanstack/react-query";
+import type { BidDto } from "./models";
+import * as client from "./client";
+"#
+        );
+
+        let mutation = operation("delete", vec![param("roomId", ParamLocation::Path)]);
+        assert_eq!(
+            HooksGenerator::header(&[&mutation], HookLib::Swr),
+            r#"// Generated with Sahih
+import useSWRMutation, { type SWRMutationConfiguration } from "swr/mutation";
+import type { BidDto } from "./models";
+import type * as operations from "./operations";
+import * as client from "./client";
+"#
+        );
+    }
+
+    #[test]
+    fn it_passes_clashing_params() {
+        let query = operation(
+            "get",
+            vec![
+                param("roomId", ParamLocation::Path),
+                param("roomId", ParamLocation::Query),
+            ],
+        );
+
+        let hooks = HooksGenerator::from(&query, HookLib::ReactQuery)
+            .build()
+            .unwrap();
+        assert!(hooks.contains(
+            "client.listBids({ ...params.path, roomIdQuery: params.query?.roomId }, { signal })"
+        ));
+    }
+}
@@ -1,15 +1,33 @@
-use indexmap::IndexSet;
+use indexmap::{IndexMap, IndexSet};
 use log::warn;
-use openapiv3::Type;
+use openapiv3::{SchemaData, Type};
 
-use super::model::{Model, ModelProperty};
+use super::model::{enumeration, Discriminated, Model, ModelProperty, PropType};
+
+/// How top-level `enum` schemas are declared, picked per project
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum EnumStyle {
+    /// `type Status = "open" | "closed"`
+    #[default]
+    Union,
+    /// `enum Status { Open = "open", Closed = "closed" }`
+    Enum,
+    /// `const Status = { Open: "open", Closed: "closed" } as const` and its union type
+    Const,
+}
 
 fn serialize_type(prop_type: &Type) -> String {
+    let values = enumeration(prop_type);
+    if !values.is_empty() {
+        let literals: Vec<String> = values.iter().map(|value| value.to_string()).collect();
+        return literals.join(" | ");
+    }
+
     let prop_type = match prop_type {
-        Type::Number(_) => "number",
+        Type::Number(_) | Type::Integer(_) => "number",
         Type::String(_) => "string",
         Type::Boolean {} => "boolean",
-        // TODO: Recursive Array implementation
         _ => {
             warn!(
                 "Serialization of type {:?} is not supported yet, collapsing to unknown",
@@ -22,65 +40,425 @@ fn serialize_type(prop_type: &Type) -> String {
     prop_type.to_string()
 }
 
+pub(super) fn serialize_prop_type(prop_type: &PropType) -> String {
+    match prop_type {
+        PropType::Type(base_type) => serialize_type(base_type),
+        PropType::Reference(model_ref) => model_ref.name.clone(),
+        PropType::Array(array) => {
+            let items = serialize_prop_type(&array.items);
+            if items.contains(['|', '&']) {
+                format!("({})[]", items)
+            } else {
+                format!("{}[]", items)
+            }
+        }
+        PropType::AllOf(members) => serialize_members(members, " & "),
+        PropType::OneOf(members) | PropType::AnyOf(members) => serialize_members(members, " | "),
+        PropType::Discriminated(union) => {
+            let variants: Vec<String> = union
+                .variants
+                .iter()
+                .map(|(tag, model_ref)| {
+                    format!(
+                        "({} & {})",
+                        model_ref.name,
+                        serialize_tag(&union.property_name, tag)
+                    )
+                })
+                .collect();
+            variants.join(" | ")
+        }
+        // `Record` can't take the alias it is declaring, an index signature can
+        PropType::Map(values) if values.references().iter().any(|r| r.recursive) => {
+            format!("{{ [key: string]: {} }}", serialize_prop_type(values))
+        }
+        PropType::Map(values) => format!("Record<string, {}>", serialize_prop_type(values)),
+        PropType::Date => String::from("Date"),
+        PropType::BigInt => String::from("bigint"),
+        PropType::Any => String::from("unknown"),
+    }
+}
+
+/// Object type literal narrowing the discriminator property to one tag
+fn serialize_tag(property_name: &str, tag: &str) -> String {
+    format!(
+        "{{ {}: {} }}",
+        serialize_prop_name(property_name),
+        serde_json::to_string(tag).unwrap()
+    )
+}
+
+/// Suffixes repeated names with a counter, a second `InStock` becomes `InStock2`
+fn deduplicate_names(names: impl Iterator<Item = String>) -> Vec<String> {
+    let mut unique: Vec<String> = vec![];
+    for name in names {
+        let mut candidate = name.clone();
+        let mut suffix = 1;
+        while unique.contains(&candidate) {
+            suffix += 1;
+            candidate = format!("{}{}", name, suffix);
+        }
+        unique.push(candidate);
+    }
+
+    unique
+}
+
+fn serialize_guards(name: &str, union: &Discriminated) -> Vec<String> {
+    let guards = deduplicate_names(union.variants.keys().map(|tag| pascal_case(tag)));
+
+    union
+        .variants
+        .iter()
+        .zip(guards)
+        .map(|((tag, model_ref), guard)| {
+            format!(
+                "export function is{name}{guard}(value: {name}): value is {variant} & {narrowed} {{\n\treturn value[{property}] === {tag};\n}}",
+                name = name,
+                guard = guard,
+                variant = model_ref.name,
+                narrowed = serialize_tag(&union.property_name, tag),
+                property = serde_json::to_string(&union.property_name).unwrap(),
+                tag = serde_json::to_string(tag).unwrap(),
+            )
+        })
+        .collect()
+}
+
+fn serialize_members(members: &[PropType], separator: &str) -> String {
+    let members: Vec<String> = members
+        .iter()
+        .map(|member| {
+            let member = serialize_prop_type(member);
+            // Intersections bind tighter than unions
+            if member.contains(['|', '&']) {
+                format!("({})", member)
+            } else {
+                member
+            }
+        })
+        .collect();
+
+    members.join(separator)
+}
+
+pub(crate) fn pascal_case(name: &str) -> String {
+    name.split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|chunk| !chunk.is_empty())
+        .map(|chunk| {
+            let mut chars = chunk.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+pub(super) fn is_identifier(name: &str) -> bool {
+    !name.is_empty()
+        && !name.starts_with(|c: char| c.is_ascii_digit())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+}
+
+pub(super) fn serialize_prop_name(name: &str) -> String {
+    if is_identifier(name) {
+        name.to_string()
+    } else {
+        format!("\"{}\"", name)
+    }
+}
+
 fn serialize_property(prop: &ModelProperty, is_required: bool) -> String {
     let ModelProperty {
         name,
-        data: _, // TODO: Advanced Validation
+        data,
         prop_type,
+        required: _,
     } = prop;
-    let literal_type = serialize_type(prop_type);
-
-    let name = name.clone();
-
-    let name = if name.contains(' ') {
-        format!("\"{}\"", name)
-    } else {
-        name
-    };
+    let literal_type = serialize_nullable(serialize_prop_type(prop_type), data.nullable);
 
     format!(
-        "{}{optional_op}: {}",
-        name,
+        "{}{}{optional_op}: {}",
+        serialize_doc(data, "\t"),
+        serialize_prop_name(name),
         literal_type,
         optional_op = if is_required { "" } else { "?" }
     )
 }
 
+/// JSDoc block of the documentation fields, every line but the first is prefixed with `indent`
+fn serialize_doc(data: &SchemaData, indent: &str) -> String {
+    let mut lines: Vec<String> = vec![];
+    let mut push_text = |text: &str| {
+        if !lines.is_empty() {
+            lines.push(String::new());
+        }
+        lines.extend(text.trim().lines().map(str::to_owned));
+    };
+
+    if let Some(title) = &data.title {
+        push_text(title);
+    }
+    if let Some(description) = &data.description {
+        push_text(description);
+    }
+    if let Some(example) = &data.example {
+        lines.push(format!("@example {}", example));
+    }
+    if data.deprecated {
+        lines.push(String::from("@deprecated"));
+    }
+    if let Some(external_docs) = &data.external_docs {
+        lines.push(match &external_docs.description {
+            Some(description) => format!("@see {{@link {} {}}}", external_docs.url, description),
+            None => format!("@see {}", external_docs.url),
+        });
+    }
+
+    // A `*/` in the text would end the comment early
+    let lines: Vec<String> = lines
+        .iter()
+        .map(|line| line.replace("*/", "*\\/"))
+        .collect();
+    match lines.as_slice() {
+        [] => String::new(),
+        [line] => format!("/** {} */\n{}", line, indent),
+        lines => {
+            let body: String = lines
+                .iter()
+                .map(|line| {
+                    format!(
+                        "{} *{}{}\n",
+                        indent,
+                        if line.is_empty() { "" } else { " " },
+                        line
+                    )
+                })
+                .collect();
+            format!("/**\n{}{} */\n{}", body, indent, indent)
+        }
+    }
+}
+
+/// Member name of an enumerated value, e.g. `in-progress` is `InProgress` and `-1` is `ValueMinus1`
+fn enum_member_name(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(value) => {
+            let name = pascal_case(value);
+            if is_identifier(&name) {
+                name
+            } else {
+                format!("Value{}", name)
+            }
+        }
+        value => format!(
+            "Value{}",
+            value.to_string().replace('-', "Minus").replace('.', "_")
+        ),
+    }
+}
+
+/// Member names of the enumerated values, colliding names are suffixed
+fn enum_member_names(values: &[serde_json::Value]) -> Vec<String> {
+    deduplicate_names(values.iter().map(enum_member_name))
+}
+
+/// Value type of the index signature, declared properties have to be assignable to it
+fn serialize_index_signature(model: &Model, values: &PropType) -> String {
+    // Inherited properties aren't known here
+    if !model.extends.is_empty() {
+        return String::from("unknown");
+    }
+
+    let mut types = IndexSet::new();
+    types.insert(serialize_prop_type(values));
+    for prop in model.properties.values() {
+        types.insert(serialize_nullable(
+            serialize_prop_type(&prop.prop_type),
+            prop.data.nullable,
+        ));
+        if !prop.required {
+            types.insert(String::from("undefined"));
+        }
+    }
+
+    if types.contains("unknown") {
+        return String::from("unknown");
+    }
+    let types: Vec<String> = types.into_iter().collect();
+    types.join(" | ")
+}
+
+fn serialize_nullable(literal_type: String, nullable: bool) -> String {
+    if nullable {
+        format!("{} | null", literal_type)
+    } else {
+        literal_type
+    }
+}
+
 pub struct InterfaceGenerator {
     pub name: String,
+    doc: String,
     properties: IndexSet<String>,
+    /// Serialized `default` of the properties declaring one
+    defaults: IndexMap<String, String>,
+    extends: Vec<String>,
+    alias: Option<String>,
+    guards: Vec<String>,
+    /// Value type of the undeclared properties
+    index_signature: Option<String>,
+    /// Values of a top-level `enum` schema
+    enumeration: Option<Vec<serde_json::Value>>,
+    pub enum_style: EnumStyle,
 }
 
 impl InterfaceGenerator {
     pub fn new(name: &str) -> Self {
         Self {
             name: name.to_string(),
+            doc: String::new(),
             properties: IndexSet::new(),
+            defaults: IndexMap::new(),
+            extends: vec![],
+            alias: None,
+            guards: vec![],
+            index_signature: None,
+            enumeration: None,
+            enum_style: EnumStyle::default(),
         }
     }
 
     pub fn from(model: &Model) -> InterfaceGenerator {
         let mut generator = InterfaceGenerator::new(&model.name);
+        generator.doc = serialize_doc(&model.data, "");
         for (_, prop_type) in &model.properties {
             generator.register_property(prop_type);
         }
 
+        generator.extends = model
+            .extends
+            .iter()
+            .map(|model_ref| model_ref.name.clone())
+            .collect();
+        if let Some(PropType::Discriminated(union)) = &model.alias {
+            generator.guards = serialize_guards(&model.name, union);
+        }
+        // Guards read the tag of the value, they can't be given null
+        let nullable = model.data.nullable && generator.guards.is_empty();
+        generator.alias = model
+            .alias
+            .as_ref()
+            .map(|alias| serialize_nullable(serialize_prop_type(alias), nullable));
+        generator.enumeration = model.enumeration();
+        generator.index_signature = model
+            .additional_properties
+            .as_ref()
+            .map(|values| serialize_index_signature(model, values));
+
         generator
     }
 
     pub fn register_property(&mut self, prop: &ModelProperty) {
-        self.properties.insert(serialize_property(prop, true));
+        self.properties
+            .insert(serialize_property(prop, prop.required));
+        if let Some(default) = &prop.data.default {
+            self.defaults
+                .insert(serialize_prop_name(&prop.name), default.to_string());
+        }
+    }
+
+    /// Object of the declared defaults, e.g. to initialise forms
+    pub fn build_defaults(&self) -> Option<String> {
+        if self.defaults.is_empty() {
+            return None;
+        }
+
+        let values: String = self
+            .defaults
+            .iter()
+            .map(|(name, value)| format!("\n\t{}: {},", name, value))
+            .collect();
+
+        Some(format!(
+            "export const {name}Defaults: Partial<{name}> = {{{values}\n}};",
+            name = self.name,
+            values = values
+        ))
+    }
+
+    /// Enumeration declared as a TypeScript `enum` or an `as const` object
+    fn build_enum(&self, values: &[serde_json::Value]) -> Option<String> {
+        let members = enum_member_names(values).into_iter().zip(values);
+        let declaration = match self.enum_style {
+            EnumStyle::Union => return None,
+            EnumStyle::Enum => {
+                let members: String = members
+                    .map(|(name, value)| format!("\n\t{} = {},", name, value))
+                    .collect();
+                format!("export enum {} {{{}\n}}", self.name, members)
+            }
+            EnumStyle::Const => {
+                let members: String = members
+                    .map(|(name, value)| format!("\n\t{}: {},", name, value))
+                    .collect();
+                format!(
+                    "export const {name} = {{{members}\n}} as const;\nexport type {name} = (typeof {name})[keyof typeof {name}];",
+                    name = self.name,
+                    members = members
+                )
+            }
+        };
+
+        Some(format!("{}{}", self.doc, declaration))
     }
 
     pub fn build(&self) -> String {
+        if let Some(declaration) = self
+            .enumeration
+            .as_ref()
+            .and_then(|values| self.build_enum(values))
+        {
+            return declaration;
+        }
+
+        if let Some(alias) = &self.alias {
+            let guards: String = self
+                .guards
+                .iter()
+                .map(|guard| format!("\n{}", guard))
+                .collect();
+            return format!(
+                "{}export type {} = {};{}",
+                self.doc, self.name, alias, guards
+            );
+        }
+
+        let extends = if self.extends.is_empty() {
+            String::new()
+        } else {
+            format!(" extends {}", self.extends.join(", "))
+        };
+
+        let index_signature = self
+            .index_signature
+            .iter()
+            .map(|values| format!("[key: string]: {}", values));
         let props_literal = self
             .properties
             .iter()
+            .cloned()
+            .chain(index_signature)
             .fold(String::new(), |sum, prop| format!("{}\n\t{}", sum, prop));
 
         format!(
-            "export interface {name} {{{props}\n}}",
+            "{doc}export interface {name}{extends} {{{props}\n}}",
+            doc = self.doc,
             name = self.name,
+            extends = extends,
             props = props_literal
         )
     }
@@ -88,11 +466,18 @@ impl InterfaceGenerator {
 
 #[cfg(test)]
 mod tests {
-    use openapiv3::{NumberType, SchemaData, StringType, Type};
+    use indexmap::IndexMap;
+    use openapiv3::{
+        NumberType, SchemaData, StringFormat, StringType, Type, VariantOrUnknownOrEmpty,
+    };
 
     use crate::codegen::{
-        interface::{serialize_property, serialize_type},
-        model::ModelProperty,
+        interface::{
+            enum_member_name, enum_member_names, serialize_guards, serialize_prop_type,
+            serialize_property, serialize_type,
+        },
+        model::{ArrayProp, Discriminated, Model, ModelProperty, ModelRef, PropType},
+        EnumStyle, InterfaceGenerator,
     };
 
     #[test]
@@ -128,7 +513,8 @@ mod tests {
         let model_prop = ModelProperty {
             name: "testprop".to_string(),
             data: schema_data,
-            prop_type: Type::Boolean {},
+            prop_type: PropType::Type(Type::Boolean {}),
+            required: true,
         };
 
         assert_eq!(serialize_property(&model_prop, true), "testprop: boolean");
@@ -142,9 +528,304 @@ mod tests {
         let model_prop = ModelProperty {
             name: "testprop".to_string(),
             data: schema_data,
-            prop_type: Type::Boolean {},
+            prop_type: PropType::Type(Type::Boolean {}),
+            required: false,
         };
 
         assert_eq!(serialize_property(&model_prop, false), "testprop?: boolean");
     }
+
+    #[test]
+    fn it_serializes_reference_prop() {
+        let model_prop = ModelProperty {
+            name: "bidder".to_string(),
+            data: SchemaData::default(),
+            prop_type: PropType::Reference(ModelRef::new("BidderDto")),
+            required: true,
+        };
+
+        assert_eq!(serialize_property(&model_prop, true), "bidder: BidderDto");
+    }
+
+    fn array_of(items: PropType) -> PropType {
+        PropType::Array(ArrayProp {
+            items: Box::new(items),
+            min_items: None,
+            max_items: None,
+            unique_items: false,
+        })
+    }
+
+    #[test]
+    fn it_serializes_arrays() {
+        let strings = array_of(PropType::Type(Type::String(StringType::default())));
+        assert_eq!(serialize_prop_type(&strings), "string[]");
+
+        let matrix = array_of(array_of(PropType::Type(
+            Type::Number(NumberType::default()),
+        )));
+        assert_eq!(serialize_prop_type(&matrix), "number[][]");
+
+        let bids = array_of(PropType::Reference(ModelRef::new("BidDto")));
+        assert_eq!(serialize_prop_type(&bids), "BidDto[]");
+    }
+
+    #[test]
+    fn it_serializes_nullable_props_and_defaults() {
+        let mut model = Model::new("Settings", SchemaData::default());
+        let data = SchemaData {
+            nullable: true,
+            default: Some(serde_json::json!("dark")),
+            ..SchemaData::default()
+        };
+        model.properties.insert(
+            "theme".to_string(),
+            ModelProperty {
+                name: "theme".to_string(),
+                data,
+                prop_type: PropType::Type(Type::String(StringType::default())),
+                required: true,
+            },
+        );
+
+        let generator = InterfaceGenerator::from(&model);
+        assert_eq!(
+            generator.build(),
+            "export interface Settings {\n\ttheme: string | null\n}"
+        );
+        assert_eq!(
+            generator.build_defaults().unwrap(),
+            "export const SettingsDefaults: Partial<Settings> = {\n\ttheme: \"dark\",\n};"
+        );
+    }
+
+    #[test]
+    fn it_serializes_jsdoc() {
+        let mut model = Model::new(
+            "Bid",
+            SchemaData {
+                description: Some("A bid placed in an auction".to_string()),
+                ..SchemaData::default()
+            },
+        );
+        let data = SchemaData {
+            title: Some("Amount".to_string()),
+            description: Some("In cents,\nnever */ negative".to_string()),
+            example: Some(serde_json::json!(1500)),
+            deprecated: true,
+            ..SchemaData::default()
+        };
+        model.properties.insert(
+            "amount".to_string(),
+            ModelProperty {
+                name: "amount".to_string(),
+                data,
+                prop_type: PropType::Type(Type::Number(NumberType::default())),
+                required: true,
+            },
+        );
+
+        assert_eq!(
+            InterfaceGenerator::from(&model).build(),
+            "/** A bid placed in an auction */\nexport interface Bid {\n\t/**\n\t * Amount\n\t *\n\t * In cents,\n\t * never *\\/ negative\n\t * @example 1500\n\t * @deprecated\n\t */\n\tamount: number\n}"
+        );
+    }
+
+    #[test]
+    fn it_serializes_date_times_as_dates() {
+        let date_time = PropType::Type(Type::String(StringType {
+            format: VariantOrUnknownOrEmpty::Item(StringFormat::DateTime),
+            ..StringType::default()
+        }));
+        let mut model = Model::new("Auction", SchemaData::default());
+        model.alias = Some(array_of(date_time));
+        assert_eq!(
+            serialize_prop_type(model.alias.as_ref().unwrap()),
+            "string[]"
+        );
+
+        model.use_date_objects();
+        assert_eq!(serialize_prop_type(model.alias.as_ref().unwrap()), "Date[]");
+    }
+
+    #[test]
+    fn it_builds_interface_with_optional_props() {
+        let mut model = Model::new("BidderDto", SchemaData::default());
+        for (name, required) in [("name", true), ("avatarUrl", false)] {
+            model.properties.insert(
+                name.to_string(),
+                ModelProperty {
+                    name: name.to_string(),
+                    data: SchemaData::default(),
+                    prop_type: PropType::Type(Type::String(StringType::default())),
+                    required,
+                },
+            );
+        }
+
+        assert_eq!(
+            InterfaceGenerator::from(&model).build(),
+            "export interface BidderDto {\n\tname: string\n\tavatarUrl?: string\n}"
+        );
+    }
+
+    #[test]
+    fn it_serializes_compositions() {
+        let pet = PropType::Reference(ModelRef::new("Pet"));
+        let union = PropType::OneOf(vec![
+            PropType::Reference(ModelRef::new("Cat")),
+            PropType::Reference(ModelRef::new("Dog")),
+        ]);
+
+        assert_eq!(serialize_prop_type(&union), "Cat | Dog");
+        assert_eq!(
+            serialize_prop_type(&PropType::AllOf(vec![pet, union])),
+            "Pet & (Cat | Dog)"
+        );
+        assert_eq!(serialize_prop_type(&PropType::Any), "unknown");
+    }
+
+    #[test]
+    fn it_serializes_additional_properties() {
+        let labels = PropType::Map(Box::new(PropType::Type(
+            Type::String(StringType::default()),
+        )));
+        assert_eq!(serialize_prop_type(&labels), "Record<string, string>");
+
+        let tree = PropType::Map(Box::new(PropType::Reference(ModelRef {
+            name: "Tree".to_string(),
+            recursive: true,
+        })));
+        assert_eq!(serialize_prop_type(&tree), "{ [key: string]: Tree }");
+
+        let mut model = Model::new("Settings", SchemaData::default());
+        model.additional_properties = Some(PropType::Type(Type::Number(NumberType::default())));
+        model.properties.insert(
+            "labels".to_string(),
+            ModelProperty {
+                name: "labels".to_string(),
+                data: SchemaData::default(),
+                prop_type: labels,
+                required: false,
+            },
+        );
+        assert_eq!(
+            InterfaceGenerator::from(&model).build(),
+            "export interface Settings {\n\tlabels?: Record<string, string>\n\t[key: string]: number | Record<string, string> | undefined\n}"
+        );
+    }
+
+    #[test]
+    fn it_builds_extending_interface() {
+        let mut model = Model::new("Dog", SchemaData::default());
+        model.extends.push(ModelRef::new("Pet"));
+        model.properties.insert(
+            "barks".to_string(),
+            ModelProperty {
+                name: "barks".to_string(),
+                data: SchemaData::default(),
+                prop_type: PropType::Type(Type::Boolean {}),
+                required: true,
+            },
+        );
+
+        assert_eq!(
+            InterfaceGenerator::from(&model).build(),
+            "export interface Dog extends Pet {\n\tbarks: boolean\n}"
+        );
+    }
+
+    #[test]
+    fn it_builds_alias() {
+        let mut model = Model::new("Pet", SchemaData::default());
+        model.alias = Some(PropType::AnyOf(vec![
+            PropType::Reference(ModelRef::new("Cat")),
+            PropType::Reference(ModelRef::new("Dog")),
+        ]));
+
+        assert_eq!(
+            InterfaceGenerator::from(&model).build(),
+            "export type Pet = Cat | Dog;"
+        );
+    }
+
+    #[test]
+    fn it_builds_enums() {
+        let mut model = Model::new("AuctionStatus", SchemaData::default());
+        model.alias = Some(PropType::Type(Type::String(StringType {
+            enumeration: vec!["open".to_string(), "in-progress".to_string()],
+            ..StringType::default()
+        })));
+        let build = |enum_style: EnumStyle| {
+            let mut generator = InterfaceGenerator::from(&model);
+            generator.enum_style = enum_style;
+            generator.build()
+        };
+
+        assert_eq!(
+            build(EnumStyle::Union),
+            r#"export type AuctionStatus = "open" | "in-progress";"#
+        );
+        assert_eq!(
+            build(EnumStyle::Enum),
+            "export enum AuctionStatus {\n\tOpen = \"open\",\n\tInProgress = \"in-progress\",\n}"
+        );
+        assert_eq!(
+            build(EnumStyle::Const),
+            "export const AuctionStatus = {\n\tOpen: \"open\",\n\tInProgress: \"in-progress\",\n} as const;\nexport type AuctionStatus = (typeof AuctionStatus)[keyof typeof AuctionStatus];"
+        );
+    }
+
+    #[test]
+    fn it_names_enum_members() {
+        assert_eq!(enum_member_name(&serde_json::json!("in_stock")), "InStock");
+        assert_eq!(enum_member_name(&serde_json::json!("2xl")), "Value2xl");
+        assert_eq!(enum_member_name(&serde_json::json!(-1.5)), "ValueMinus1_5");
+        assert_eq!(
+            enum_member_names(&[
+                serde_json::json!("in-progress"),
+                serde_json::json!("in_progress")
+            ]),
+            vec!["InProgress", "InProgress2"]
+        );
+    }
+
+    #[test]
+    fn it_deduplicates_guard_names() {
+        let mut variants = IndexMap::new();
+        variants.insert("in-stock".to_string(), ModelRef::new("Stocked"));
+        variants.insert("in_stock".to_string(), ModelRef::new("Restocked"));
+        let union = Discriminated {
+            property_name: "status".to_string(),
+            variants,
+        };
+
+        let guards = serialize_guards("Item", &union);
+        assert!(guards[0].starts_with("export function isItemInStock(value: Item)"));
+        assert!(guards[1].starts_with("export function isItemInStock2(value: Item)"));
+    }
+
+    #[test]
+    fn it_builds_discriminated_union_with_guards() {
+        let mut variants = IndexMap::new();
+        variants.insert("bid_placed".to_string(), ModelRef::new("BidPlaced"));
+        variants.insert("ended".to_string(), ModelRef::new("AuctionEnded"));
+
+        let mut model = Model::new("AuctionEvent", SchemaData::default());
+        model.alias = Some(PropType::Discriminated(Discriminated {
+            property_name: "type".to_string(),
+            variants,
+        }));
+
+        assert_eq!(
+            InterfaceGenerator::from(&model).build(),
+            r#"export type AuctionEvent = (BidPlaced & { type: "bid_placed" }) | (AuctionEnded & { type: "ended" });
+export function isAuctionEventBidPlaced(value: AuctionEvent): value is BidPlaced & { type: "bid_placed" } {
+	return value["type"] === "bid_placed";
+}
+export function isAuctionEventEnded(value: AuctionEvent): value is AuctionEnded & { type: "ended" } {
+	return value["type"] === "ended";
+}"#
+        );
+    }
 }
@@ -1,6 +1,19 @@
+mod client;
+mod hooks;
 mod interface;
 pub mod model;
+pub mod operation;
+mod operation_types;
+mod pattern;
 mod validation;
+mod yup;
+mod zod;
 
+pub use client::*;
+pub use hooks::{HookLib, HooksGenerator};
 pub use interface::*;
+pub use operation_types::OperationTypesGenerator;
+pub use pattern::JsPattern;
 pub use validation::*;
+pub use yup::Yup;
+pub use zod::Zod;
@@ -1,20 +1,399 @@
-use indexmap::IndexMap;
-use openapiv3::{SchemaData, Type};
+use indexmap::{IndexMap, IndexSet};
+use openapiv3::{
+    IntegerFormat, IntegerType, SchemaData, StringFormat, StringType, Type, VariantOrUnknownOrEmpty,
+};
 
-pub enum REQUIRED<'a> {
-    REQUIRED(&'a ModelProperty),
+/// Prefix of the local references to component schemas
+pub const SCHEMA_REF_PREFIX: &str = "#/components/schemas/";
+
+/// Escapes a JSON pointer segment
+pub fn pointer_segment(segment: &str) -> String {
+    segment.replace('~', "~0").replace('/', "~1")
+}
+
+/// Pointer of the component schema declaring the model
+pub fn schema_pointer(name: &str) -> String {
+    format!("{}{}", SCHEMA_REF_PREFIX, pointer_segment(name))
+}
+
+fn date_object(base_type: &Type) -> Option<PropType> {
+    match base_type {
+        Type::String(StringType {
+            format: VariantOrUnknownOrEmpty::Item(StringFormat::DateTime),
+            ..
+        }) => Some(PropType::Date),
+        _ => None,
+    }
+}
+
+fn bigint(base_type: &Type) -> Option<PropType> {
+    match base_type {
+        Type::Integer(IntegerType {
+            format: VariantOrUnknownOrEmpty::Item(IntegerFormat::Int64),
+            ..
+        }) => Some(PropType::BigInt),
+        _ => None,
+    }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Model {
     pub name: String,
+    /// JSON pointer of the schema in the spec, reported in diagnostics
+    pub pointer: String,
     pub data: SchemaData,
     pub properties: IndexMap<String, ModelProperty>,
+    /// Models whose properties are inherited through `allOf`
+    pub extends: Vec<ModelRef>,
+    /// Set when the model isn't an object but a named alias of another type
+    pub alias: Option<PropType>,
+    /// Type of the undeclared properties, `Any` for `additionalProperties: true`
+    pub additional_properties: Option<PropType>,
+    /// Set by `additionalProperties: false`, undeclared properties are rejected
+    pub strict: bool,
+}
+
+impl Model {
+    pub fn new(name: &str, data: SchemaData) -> Self {
+        Self {
+            name: name.to_owned(),
+            pointer: schema_pointer(name),
+            data,
+            properties: IndexMap::new(),
+            extends: vec![],
+            alias: None,
+            additional_properties: None,
+            strict: false,
+        }
+    }
+
+    pub fn references(&self) -> Vec<&ModelRef> {
+        let props = self.properties.values().map(|prop| &prop.prop_type);
+
+        props
+            .chain(self.alias.iter())
+            .chain(self.additional_properties.iter())
+            .flat_map(|prop_type| prop_type.references())
+            .chain(self.extends.iter())
+            .collect()
+    }
+
+    pub fn references_mut(&mut self) -> Vec<&mut ModelRef> {
+        let props = self.properties.values_mut().map(|prop| &mut prop.prop_type);
+
+        props
+            .chain(self.alias.iter_mut())
+            .chain(self.additional_properties.iter_mut())
+            .flat_map(|prop_type| prop_type.references_mut())
+            .chain(self.extends.iter_mut())
+            .collect()
+    }
+
+    fn retype(&mut self, retyped: fn(&Type) -> Option<PropType>) {
+        let props = self.properties.values_mut().map(|prop| &mut prop.prop_type);
+
+        let additional = self.additional_properties.iter_mut();
+        for prop_type in props.chain(self.alias.iter_mut()).chain(additional) {
+            prop_type.retype(retyped);
+        }
+    }
+
+    /// Values of a top-level `enum` schema
+    pub fn enumeration(&self) -> Option<Vec<serde_json::Value>> {
+        match &self.alias {
+            Some(PropType::Type(base_type)) => {
+                let values = enumeration(base_type);
+                Some(values).filter(|values| !values.is_empty())
+            }
+            _ => None,
+        }
+    }
+
+    /// Types every `date-time` string of the model as a `Date`
+    pub fn use_date_objects(&mut self) {
+        self.retype(date_object);
+    }
+
+    /// Types every `int64` integer of the model as a `bigint`
+    pub fn use_bigints(&mut self) {
+        self.retype(bigint);
+    }
 }
 
-#[derive(Debug)]
+/// Derives a `{name}{suffix}` variant, without the properties `excluded` matches, of every model
+/// holding such properties directly or through the models it references. Variants are keyed by
+/// the name of their model and reference the variants of the other models.
+pub fn derive_variants(
+    models: &[Model],
+    suffix: &str,
+    excluded: fn(&SchemaData) -> bool,
+) -> IndexMap<String, Model> {
+    let mut derived: IndexSet<&str> = models
+        .iter()
+        .filter(|model| model.properties.values().any(|prop| excluded(&prop.data)))
+        .map(|model| model.name.as_str())
+        .collect();
+    loop {
+        let count = derived.len();
+        for model in models {
+            if model
+                .references()
+                .iter()
+                .any(|model_ref| derived.contains(model_ref.name.as_str()))
+            {
+                derived.insert(&model.name);
+            }
+        }
+        if derived.len() == count {
+            break;
+        }
+    }
+
+    models
+        .iter()
+        .filter(|model| derived.contains(model.name.as_str()))
+        .map(|model| {
+            let mut variant = model.clone();
+            variant.name = format!("{}{}", model.name, suffix);
+            variant.properties.retain(|_, prop| !excluded(&prop.data));
+            for model_ref in variant.references_mut() {
+                if derived.contains(model_ref.name.as_str()) {
+                    model_ref.name.push_str(suffix);
+                }
+            }
+
+            (model.name.clone(), variant)
+        })
+        .collect()
+}
+
+/// Values listed in the `enum` of a string, number or integer schema
+pub fn enumeration(base_type: &Type) -> Vec<serde_json::Value> {
+    match base_type {
+        Type::String(string_type) => string_type
+            .enumeration
+            .iter()
+            .map(|value| serde_json::json!(value))
+            .collect(),
+        // Whole numbers are kept as integers, so they read `1` rather than `1.0`
+        Type::Number(number_type) => number_type
+            .enumeration
+            .iter()
+            .map(
+                |value| match value.fract() == 0.0 && value.abs() < i64::MAX as f64 {
+                    true => serde_json::json!(*value as i64),
+                    false => serde_json::json!(value),
+                },
+            )
+            .collect(),
+        Type::Integer(integer_type) => integer_type
+            .enumeration
+            .iter()
+            .map(|value| serde_json::json!(value))
+            .collect(),
+        _ => vec![],
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct ModelProperty {
     pub name: String,
     pub data: SchemaData,
-    pub prop_type: Type,
+    pub prop_type: PropType,
+    /// Listed in the `required` array of the parent object
+    pub required: bool,
+}
+
+/// Reference to another model of the same spec
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModelRef {
+    pub name: String,
+    /// Set when the reference closes a cycle, the target model is then declared after the referrer
+    pub recursive: bool,
+}
+
+impl ModelRef {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+            recursive: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ArrayProp {
+    pub items: Box<PropType>,
+    pub min_items: Option<usize>,
+    pub max_items: Option<usize>,
+    pub unique_items: bool,
+}
+
+/// `oneOf` whose variants are told apart by the value of a tag property
+#[derive(Debug, Clone)]
+pub struct Discriminated {
+    pub property_name: String,
+    /// Variant model of every tag value
+    pub variants: IndexMap<String, ModelRef>,
+}
+
+#[derive(Debug, Clone)]
+pub enum PropType {
+    Type(Type),
+    Reference(ModelRef),
+    Array(ArrayProp),
+    /// Value matching every member (`allOf`)
+    AllOf(Vec<PropType>),
+    /// Value matching exactly one member (`oneOf`)
+    OneOf(Vec<PropType>),
+    /// Value matching at least one member (`anyOf`)
+    AnyOf(Vec<PropType>),
+    Discriminated(Discriminated),
+    /// Object of undeclared keys only, with values of the boxed type (`additionalProperties`)
+    Map(Box<PropType>),
+    /// `date-time` string the validators convert to a JS `Date`
+    Date,
+    /// `int64` integer the validators convert to a JS `bigint`
+    BigInt,
+    /// Unconstrained value, `not` schemas end up here as TypeScript can't express them
+    Any,
+}
+
+impl PropType {
+    pub fn references(&self) -> Vec<&ModelRef> {
+        match self {
+            PropType::Reference(model_ref) => vec![model_ref],
+            PropType::Array(array) => array.items.references(),
+            PropType::Map(values) => values.references(),
+            PropType::AllOf(members) | PropType::OneOf(members) | PropType::AnyOf(members) => {
+                members.iter().flat_map(PropType::references).collect()
+            }
+            PropType::Discriminated(union) => union.variants.values().collect(),
+            PropType::Type(_) | PropType::Date | PropType::BigInt | PropType::Any => vec![],
+        }
+    }
+
+    pub fn references_mut(&mut self) -> Vec<&mut ModelRef> {
+        match self {
+            PropType::Reference(model_ref) => vec![model_ref],
+            PropType::Array(array) => array.items.references_mut(),
+            PropType::Map(values) => values.references_mut(),
+            PropType::AllOf(members) | PropType::OneOf(members) | PropType::AnyOf(members) => {
+                members
+                    .iter_mut()
+                    .flat_map(PropType::references_mut)
+                    .collect()
+            }
+            PropType::Discriminated(union) => union.variants.values_mut().collect(),
+            PropType::Type(_) | PropType::Date | PropType::BigInt | PropType::Any => vec![],
+        }
+    }
+
+    /// Types every `date-time` string of the type as a `Date`
+    pub fn use_date_objects(&mut self) {
+        self.retype(date_object);
+    }
+
+    /// Types every `int64` integer of the type as a `bigint`
+    pub fn use_bigints(&mut self) {
+        self.retype(bigint);
+    }
+
+    /// Replaces the base types `retyped` maps to another type
+    fn retype(&mut self, retyped: fn(&Type) -> Option<PropType>) {
+        match self {
+            PropType::Type(base_type) => {
+                if let Some(prop_type) = retyped(base_type) {
+                    *self = prop_type;
+                }
+            }
+            PropType::Array(array) => array.items.retype(retyped),
+            PropType::Map(values) => values.retype(retyped),
+            PropType::AllOf(members) | PropType::OneOf(members) | PropType::AnyOf(members) => {
+                for member in members {
+                    member.retype(retyped);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use openapiv3::{SchemaData, StringType, Type};
+
+    use super::{derive_variants, ArrayProp, Model, ModelProperty, ModelRef, PropType};
+
+    fn property(name: &str, prop_type: PropType, data: SchemaData) -> ModelProperty {
+        ModelProperty {
+            name: name.to_string(),
+            data,
+            prop_type,
+            required: true,
+        }
+    }
+
+    fn string() -> PropType {
+        PropType::Type(Type::String(StringType::default()))
+    }
+
+    fn model(name: &str, properties: Vec<ModelProperty>) -> Model {
+        let mut model = Model::new(name, SchemaData::default());
+        for prop in properties {
+            model.properties.insert(prop.name.clone(), prop);
+        }
+        model
+    }
+
+    #[test]
+    fn it_derives_input_output_variants() {
+        let read_only = SchemaData {
+            read_only: true,
+            ..SchemaData::default()
+        };
+        let write_only = SchemaData {
+            write_only: true,
+            ..SchemaData::default()
+        };
+        let members = PropType::Array(ArrayProp {
+            items: Box::new(PropType::Reference(ModelRef::new("User"))),
+            min_items: None,
+            max_items: None,
+            unique_items: false,
+        });
+        let models = vec![
+            model(
+                "User",
+                vec![
+                    property("id", string(), read_only),
+                    property("email", string(), SchemaData::default()),
+                    property("password", string(), write_only),
+                ],
+            ),
+            model(
+                "Team",
+                vec![
+                    property("name", string(), SchemaData::default()),
+                    property("members", members, SchemaData::default()),
+                ],
+            ),
+            model(
+                "Tag",
+                vec![property("label", string(), SchemaData::default())],
+            ),
+        ];
+
+        let inputs = derive_variants(&models, "Input", |data| data.read_only);
+        let outputs = derive_variants(&models, "Output", |data| data.write_only);
+        let prop_names = |model: &Model| model.properties.keys().cloned().collect::<Vec<_>>();
+
+        assert_eq!(inputs.keys().collect::<Vec<_>>(), vec!["User", "Team"]);
+        assert_eq!(prop_names(&inputs["User"]), vec!["email", "password"]);
+        assert_eq!(prop_names(&outputs["User"]), vec!["id", "email"]);
+
+        let team = &inputs["Team"];
+        assert_eq!(team.name, "TeamInput");
+        assert_eq!(team.references(), vec![&ModelRef::new("UserInput")]);
+    }
 }
@@ -0,0 +1,115 @@
+use super::model::PropType;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParamLocation {
+    Path,
+    Query,
+    Header,
+    Cookie,
+}
+
+#[derive(Debug)]
+pub struct OperationParam {
+    pub name: String,
+    pub location: ParamLocation,
+    pub required: bool,
+    pub prop_type: PropType,
+}
+
+#[derive(Debug)]
+pub struct OperationBody {
+    pub required: bool,
+    pub prop_type: PropType,
+}
+
+#[derive(Debug)]
+pub struct OperationResponse {
+    /// Status code, `2XX` like range or `default`
+    pub status: String,
+    /// Schema of the content, `None` when the response has no content
+    pub prop_type: Option<PropType>,
+}
+
+impl OperationResponse {
+    pub fn is_success(&self) -> bool {
+        self.status.starts_with('2')
+    }
+}
+
+#[derive(Debug)]
+pub struct Operation {
+    /// `operationId` of the operation, sanitized into a valid identifier
+    pub name: String,
+    /// Lowercase HTTP method
+    pub method: String,
+    pub path: String,
+    pub parameters: Vec<OperationParam>,
+    pub body: Option<OperationBody>,
+    /// Schema of the first successful JSON response
+    pub response: Option<PropType>,
+    /// Every declared response, in the order of the spec
+    pub responses: Vec<OperationResponse>,
+}
+
+impl Operation {
+    pub fn params_in(&self, location: ParamLocation) -> impl Iterator<Item = &OperationParam> {
+        self.parameters
+            .iter()
+            .filter(move |param| param.location == location)
+    }
+
+    /// Name of the param in the flat `params` argument of the client, query params
+    /// sharing their name with a path param are suffixed with `Query`
+    pub fn arg_name(&self, param: &OperationParam) -> String {
+        let clashes = param.location == ParamLocation::Query
+            && self
+                .params_in(ParamLocation::Path)
+                .any(|path_param| path_param.name == param.name);
+
+        if clashes {
+            format!("{}Query", param.name)
+        } else {
+            param.name.clone()
+        }
+    }
+
+    fn prop_types_mut(&mut self) -> impl Iterator<Item = &mut PropType> {
+        let params = self.parameters.iter_mut().map(|param| &mut param.prop_type);
+        let body = self.body.iter_mut().map(|body| &mut body.prop_type);
+        let responses = self
+            .responses
+            .iter_mut()
+            .flat_map(|response| &mut response.prop_type);
+
+        params
+            .chain(body)
+            .chain(self.response.iter_mut())
+            .chain(responses)
+    }
+
+    /// Types every `date-time` string of the params, body and responses as a `Date`
+    pub fn use_date_objects(&mut self) {
+        self.prop_types_mut().for_each(PropType::use_date_objects);
+    }
+
+    /// Types every `int64` integer of the params, body and responses as a `bigint`
+    pub fn use_bigints(&mut self) {
+        self.prop_types_mut().for_each(PropType::use_bigints);
+    }
+
+    pub fn references(&self) -> Vec<&str> {
+        let params = self.parameters.iter().map(|param| &param.prop_type);
+        let body = self.body.iter().map(|body| &body.prop_type);
+        let responses = self
+            .responses
+            .iter()
+            .flat_map(|response| &response.prop_type);
+
+        params
+            .chain(body)
+            .chain(responses)
+            .flat_map(|prop_type| prop_type.references())
+            .map(|model_ref| model_ref.name.as_str())
+            .collect()
+    }
+}
@@ -0,0 +1,168 @@
+use super::{
+    client::{serialize_imports, serialize_params_type},
+    interface::{pascal_case, serialize_prop_type},
+    operation::{Operation, OperationParam, OperationResponse, ParamLocation},
+};
+
+const PARAM_GROUPS: [(ParamLocation, &str); 4] = [
+    (ParamLocation::Path, "path"),
+    (ParamLocation::Query, "query"),
+    (ParamLocation::Header, "header"),
+    (ParamLocation::Cookie, "cookie"),
+];
+
+/// Literal type of the status, ranges and `default` can be any number
+fn serialize_status(status: &str) -> String {
+    match status.parse::<u16>() {
+        Ok(code) => code.to_string(),
+        Err(_) => String::from("number"),
+    }
+}
+
+fn serialize_response(response: &OperationResponse) -> String {
+    let data = match &response.prop_type {
+        Some(prop_type) => serialize_prop_type(prop_type),
+        None => String::from("undefined"),
+    };
+
+    format!(
+        "{{ status: {}; data: {} }}",
+        serialize_status(&response.status),
+        data
+    )
+}
+
+/// `XxxParams`, `XxxBody` and `XxxResponse` types of an operation, for hand-written clients
+#[derive(Debug)]
+pub struct OperationTypesGenerator<'a> {
+    operation: &'a Operation,
+}
+
+impl<'a> OperationTypesGenerator<'a> {
+    pub fn from(operation: &'a Operation) -> Self {
+        Self { operation }
+    }
+
+    pub fn header(operations: &[Operation]) -> String {
+        let header = format!("// Generated with Sahih\n{}", serialize_imports(operations));
+        format!("{}\n", header.trim_end())
+    }
+
+    /// Prefix of the type names, e.g. `AuctionControllerGetAuction`
+    pub fn type_name(&self) -> String {
+        pascal_case(&self.operation.name)
+    }
+
+    pub fn build(&self) -> String {
+        let operation = self.operation;
+        let name = self.type_name();
+        let mut declarations: Vec<String> = vec![];
+
+        let groups: String = PARAM_GROUPS
+            .iter()
+            .filter_map(|(location, group)| {
+                let params: Vec<&OperationParam> = operation.params_in(*location).collect();
+                if params.is_empty() {
+                    return None;
+                }
+
+                let required = params.iter().any(|param| param.required);
+                Some(format!(
+                    "\n\t{}{}: {};",
+                    group,
+                    if required { "" } else { "?" },
+                    serialize_params_type(&params)
+                ))
+            })
+            .collect();
+        if !groups.is_empty() {
+            declarations.push(format!("export interface {}Params {{{}\n}}", name, groups));
+        }
+
+        if let Some(body) = &operation.body {
+            declarations.push(format!(
+                "export type {}Body = {};",
+                name,
+                serialize_prop_type(&body.prop_type)
+            ));
+        }
+
+        if !operation.responses.is_empty() {
+            let variants: String = operation
+                .responses
+                .iter()
+                .map(|response| format!("\n\t| {}", serialize_response(response)))
+                .collect();
+            declarations.push(format!("export type {}Response ={};", name, variants));
+        }
+
+        declarations.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use openapiv3::{StringType, Type};
+
+    use crate::codegen::{
+        model::{ModelRef, PropType},
+        operation::{Operation, OperationBody, OperationParam, OperationResponse, ParamLocation},
+        OperationTypesGenerator,
+    };
+
+    fn string_param(name: &str, location: ParamLocation, required: bool) -> OperationParam {
+        OperationParam {
+            name: name.to_string(),
+            location,
+            required,
+            prop_type: PropType::Type(Type::String(StringType::default())),
+        }
+    }
+
+    #[test]
+    fn it_builds_operation_types() {
+        let operation = Operation {
+            name: "BidController_putBid".to_string(),
+            method: "put".to_string(),
+            path: "/rooms/{roomId}/bid".to_string(),
+            parameters: vec![
+                string_param("roomId", ParamLocation::Path, true),
+                string_param("dryRun", ParamLocation::Query, false),
+                string_param("x-request-id", ParamLocation::Header, false),
+            ],
+            body: Some(OperationBody {
+                required: true,
+                prop_type: PropType::Reference(ModelRef::new("PlacingBidInput")),
+            }),
+            response: None,
+            responses: vec![
+                OperationResponse {
+                    status: "201".to_string(),
+                    prop_type: Some(PropType::Reference(ModelRef::new("BidDto"))),
+                },
+                OperationResponse {
+                    status: "204".to_string(),
+                    prop_type: None,
+                },
+                OperationResponse {
+                    status: "default".to_string(),
+                    prop_type: Some(PropType::Any),
+                },
+            ],
+        };
+
+        assert_eq!(
+            OperationTypesGenerator::from(&operation).build(),
+            r#"export interface BidControllerPutBidParams {
+	path: { roomId: string };
+	query?: { dryRun?: string };
+	header?: { "x-request-id"?: string };
+}
+export type BidControllerPutBidBody = PlacingBidInput;
+export type BidControllerPutBidResponse =
+	| { status: 201; data: BidDto }
+	| { status: 204; data: undefined }
+	| { status: number; data: unknown };"#
+        );
+    }
+}
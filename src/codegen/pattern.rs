@@ -0,0 +1,269 @@
+/// Octet of a dotted IPv4 address, shared by the `ipv4` format rules
+pub(super) const IPV4_OCTET: &str = r"(25[0-5]|2[0-4]\d|1?\d?\d)";
+/// RFC 1123 hostname, shared by the `hostname` format rules
+pub(super) const HOSTNAME_PATTERN: &str = r"(?=.{1,253}$)[a-zA-Z0-9]([a-zA-Z0-9-]{0,61}[a-zA-Z0-9])?(\.[a-zA-Z0-9]([a-zA-Z0-9-]{0,61}[a-zA-Z0-9])?)*";
+
+/// Regular expression of a spec `pattern`, translated to ECMAScript syntax
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsPattern {
+    pub source: String,
+    pub flags: String,
+}
+
+/// Length of the `{n}`, `{n,}` or `{n,m}` quantifier starting `chars`, if it is one
+fn brace_quantifier_len(chars: &[char]) -> Option<usize> {
+    let end = chars.iter().position(|c| *c == '}')?;
+    let body: String = chars[1..end].iter().collect();
+    let mut bounds = body.splitn(2, ',');
+    let min = bounds.next()?;
+    let max = bounds.next().unwrap_or("0");
+
+    let is_number = |bound: &str| bound.chars().all(|c| c.is_ascii_digit());
+    if !min.is_empty() && is_number(min) && is_number(max) {
+        Some(end + 1)
+    } else {
+        None
+    }
+}
+
+impl JsPattern {
+    pub fn new(source: &str) -> Self {
+        Self {
+            source: source.to_owned(),
+            flags: String::new(),
+        }
+    }
+
+    /// Translates PCRE and Python specific syntax, fails on constructs JavaScript has no equivalent for
+    pub fn translate(pattern: &str) -> Result<Self, String> {
+        let chars: Vec<char> = pattern.chars().collect();
+        let mut source = String::new();
+        let mut flags = String::new();
+        let mut in_class = false;
+        let mut groups = 0usize;
+        let mut index = 0;
+
+        while index < chars.len() {
+            let c = chars[index];
+            let next = chars.get(index + 1).copied();
+
+            match c {
+                '\\' => {
+                    let escaped = next.ok_or("pattern ends with a lone backslash")?;
+                    index += 2;
+
+                    match escaped {
+                        'A' if !in_class => source.push('^'),
+                        'z' if !in_class => source.push('$'),
+                        'Z' if !in_class => source.push_str("(?=\\n?$)"),
+                        'Q' => {
+                            // Quoted sequence, everything up to `\E` is literal
+                            while index < chars.len() {
+                                if chars[index] == '\\' && chars.get(index + 1) == Some(&'E') {
+                                    index += 2;
+                                    break;
+                                }
+                                if !chars[index].is_alphanumeric() && chars[index] != ' ' {
+                                    source.push('\\');
+                                }
+                                source.push(chars[index]);
+                                index += 1;
+                            }
+                        }
+                        'G' | 'K' | 'X' | 'C' | 'R' | 'h' | 'H' | 'V' => {
+                            return Err(format!("\\{} has no JavaScript equivalent", escaped))
+                        }
+                        'p' | 'P' => {
+                            if !flags.contains('u') {
+                                flags.push('u');
+                            }
+                            source.push('\\');
+                            source.push(escaped);
+                        }
+                        _ => {
+                            source.push('\\');
+                            source.push(escaped);
+                        }
+                    }
+                    continue;
+                }
+                '[' if !in_class => {
+                    in_class = true;
+                    source.push('[');
+                    index += 1;
+                    if chars.get(index) == Some(&'^') {
+                        source.push('^');
+                        index += 1;
+                    }
+                    // A leading `]` is literal in PCRE but closes an empty class in JavaScript
+                    if chars.get(index) == Some(&']') {
+                        source.push_str("\\]");
+                        index += 1;
+                    }
+                    continue;
+                }
+                ']' if in_class => in_class = false,
+                '(' if !in_class && next == Some('?') => {
+                    let rest: String = chars[index + 2..].iter().collect();
+                    let consumed = if let Some(named) = rest.strip_prefix("P<") {
+                        groups += 1;
+                        source.push_str("(?<");
+                        rest.len() - named.len() + 2
+                    } else if let Some(backreference) = rest.strip_prefix("P=") {
+                        let name: String =
+                            backreference.chars().take_while(|c| *c != ')').collect();
+                        if name.len() == backreference.len() {
+                            return Err(String::from("unterminated named backreference"));
+                        }
+                        source.push_str(&format!("\\k<{}>", name));
+                        name.chars().count() + 5
+                    } else if rest.starts_with('>') {
+                        // Atomic groups only differ from plain ones in their backtracking
+                        groups += 1;
+                        source.push_str("(?:");
+                        3
+                    } else if rest.starts_with('#') {
+                        let comment: String = rest.chars().take_while(|c| *c != ')').collect();
+                        if comment.len() == rest.len() {
+                            return Err(String::from("unterminated comment group"));
+                        }
+                        comment.chars().count() + 3
+                    } else if rest.starts_with([':', '=', '!', '<']) {
+                        groups += 1;
+                        source.push_str("(?");
+                        2
+                    } else {
+                        let modifiers: String =
+                            rest.chars().take_while(|c| c.is_alphabetic()).collect();
+                        let closed = rest[modifiers.len()..].starts_with(')');
+                        if modifiers.is_empty() || !closed {
+                            let syntax: String = rest.chars().take(modifiers.len() + 1).collect();
+                            return Err(format!("unsupported group syntax (?{}", syntax));
+                        }
+                        if !source.is_empty() {
+                            return Err(String::from(
+                                "inline flags are only supported at the start of the pattern",
+                            ));
+                        }
+                        for modifier in modifiers.chars() {
+                            if !"ims".contains(modifier) {
+                                return Err(format!("unsupported inline flag {}", modifier));
+                            }
+                            if !flags.contains(modifier) {
+                                flags.push(modifier);
+                            }
+                        }
+                        modifiers.chars().count() + 3
+                    };
+                    index += consumed;
+                    continue;
+                }
+                '(' if !in_class => groups += 1,
+                ')' if !in_class => {
+                    groups = groups
+                        .checked_sub(1)
+                        .ok_or("unbalanced closing parenthesis")?;
+                }
+                '{' if !in_class => {
+                    if let Some(len) = brace_quantifier_len(&chars[index..]) {
+                        source.extend(&chars[index..index + len]);
+                        index += len;
+                        // Possessive quantifiers are approximated with greedy ones
+                        if chars.get(index) == Some(&'+') {
+                            index += 1;
+                        }
+                        continue;
+                    }
+                }
+                '*' | '+' | '?' if !in_class => {
+                    source.push(c);
+                    index += 1;
+                    if chars.get(index) == Some(&'+') {
+                        index += 1;
+                    }
+                    continue;
+                }
+                _ => {}
+            }
+
+            source.push(c);
+            index += 1;
+        }
+
+        if in_class {
+            return Err(String::from("unterminated character class"));
+        }
+        if groups > 0 {
+            return Err(String::from("unbalanced opening parenthesis"));
+        }
+
+        Ok(Self { source, flags })
+    }
+
+    /// Regex literal, or a `RegExp` constructor when the source can't be written as a literal
+    pub fn expression(&self) -> String {
+        let has_line_terminator = self.source.contains(['\n', '\r', '\u{2028}', '\u{2029}']);
+
+        if has_line_terminator || self.source.is_empty() {
+            let source = serde_json::to_string(&self.source).unwrap();
+            return if self.flags.is_empty() {
+                format!("new RegExp({})", source)
+            } else {
+                format!("new RegExp({}, \"{}\")", source, self.flags)
+            };
+        }
+
+        // Slashes end the literal unless escaped, even in character classes
+        let mut literal = String::new();
+        let mut escaped = false;
+        for c in self.source.chars() {
+            if c == '/' && !escaped {
+                literal.push('\\');
+            }
+            escaped = c == '\\' && !escaped;
+            literal.push(c);
+        }
+
+        format!("/{}/{}", literal, self.flags)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::JsPattern;
+
+    fn translated(pattern: &str) -> String {
+        JsPattern::translate(pattern).unwrap().expression()
+    }
+
+    #[test]
+    fn it_escapes_slashes() {
+        assert_eq!(translated("^https?://[^/]+/$"), r"/^https?:\/\/[^\/]+\/$/");
+        assert_eq!(translated(r"a\/b"), r"/a\/b/");
+    }
+
+    #[test]
+    fn it_translates_pcre_syntax() {
+        assert_eq!(translated(r"\A[a-z]++\z"), "/^[a-z]+$/");
+        assert_eq!(
+            translated(r"(?P<year>\d{4})-(?P=year)"),
+            r"/(?<year>\d{4})-\k<year>/"
+        );
+        assert_eq!(translated(r"(?>ab|a)c{2,3}+"), "/(?:ab|a)c{2,3}/");
+        assert_eq!(translated(r"(?i)^abc(?#comment)$"), "/^abc$/i");
+        assert_eq!(translated(r"[]a]\Q1+1\E"), r"/[\]a]1\+1/");
+        assert_eq!(translated(r"\p{Lu}"), r"/\p{Lu}/u");
+    }
+
+    #[test]
+    fn it_falls_back_to_constructor() {
+        assert_eq!(translated("a\nb"), r#"new RegExp("a\nb")"#);
+    }
+
+    #[test]
+    fn it_rejects_untranslatable_patterns() {
+        for pattern in [r"\Gabc", "(?R)", "a(?i)b", "[a-z", "(ab", "ab)", r"ab\"] {
+            assert!(JsPattern::translate(pattern).is_err(), "{}", pattern);
+        }
+    }
+}
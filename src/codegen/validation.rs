@@ -1,64 +1,162 @@
+use std::{collections::HashMap, fmt};
+
 use indexmap::IndexMap;
 use log::debug;
-use openapiv3::Type;
+use openapiv3::{StringFormat, Type, VariantOrUnknownOrEmpty};
+
+use super::{
+    interface::EnumStyle,
+    model::{pointer_segment, schema_pointer, Model, ModelProperty, ModelRef, PropType},
+    pattern::JsPattern,
+    yup::Yup,
+    zod::Zod,
+};
+
+/// Renders the validation rules for one validator library.
+///
+/// yup and zod ship with sahih, other libraries can be plugged in with `Sahih::register_validator`.
+pub trait ValidatorEmitter {
+    /// Import statement heading the models file
+    fn import(&self) -> String;
+
+    fn number_rule(&self, rule: &NumberRules) -> String;
+
+    fn string_rule(&self, rule: &StringRules) -> String;
 
-use super::model::{Model, ModelProperty};
+    fn array_rule(&self, rule: &ArrayRules) -> String;
 
-trait BuildableRule {
-    fn build(&self) -> String;
+    /// Full schema expression of the rules, without any presence rule
+    fn schema(&self, rules: &PropRules) -> String;
+
+    /// Schema expression of a model property, honouring whether it's required
+    fn property(&self, validation: &PropValidation) -> String;
+
+    /// Exported validator of a whole model
+    fn validator(&self, generator: &ValidationGenerator) -> String;
+
+    /// `validateResponse<T>(operation, schema, value): T` helper of the generated client, which
+    /// throws a `ResponseValidationError` with the path of the first invalid value. Responses
+    /// are returned unchecked by emitters without one.
+    fn response_validation(&self) -> Option<String> {
+        None
+    }
 }
 
-fn collect_rules<B: BuildableRule>(prefix: &str, rules: &[B]) -> String {
-    let collected_rules: String = rules.iter().map(|rule| rule.build()).collect();
+pub(super) trait BuildableRule {
+    fn build(&self, emitter: &dyn ValidatorEmitter) -> String;
+}
+
+pub(super) fn collect_rules<B: BuildableRule>(
+    prefix: &str,
+    rules: &[B],
+    emitter: &dyn ValidatorEmitter,
+) -> String {
+    let collected_rules: String = rules.iter().map(|rule| rule.build(emitter)).collect();
     format!("{}{}", prefix, collected_rules)
 }
 
-#[derive(Debug)]
+/// Library the generated validators are written for, picked per project
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+#[serde(from = "String", into = "String")]
 pub enum ValidatorLib {
+    #[default]
     Yup,
+    Zod,
+    /// Emitter registered under this name through `Sahih::register_validator`
+    Custom(String),
 }
 
-#[derive(Debug)]
+impl ValidatorLib {
+    pub fn name(&self) -> &str {
+        match self {
+            ValidatorLib::Yup => "yup",
+            ValidatorLib::Zod => "zod",
+            ValidatorLib::Custom(name) => name,
+        }
+    }
+
+    /// Built-in emitter of the library, or the one registered under its name
+    pub fn emitter<'a>(
+        &self,
+        registered: &'a HashMap<String, Box<dyn ValidatorEmitter>>,
+    ) -> Option<&'a dyn ValidatorEmitter> {
+        match self {
+            ValidatorLib::Yup => Some(&Yup),
+            ValidatorLib::Zod => Some(&Zod),
+            ValidatorLib::Custom(name) => registered.get(name).map(|emitter| emitter.as_ref()),
+        }
+    }
+}
+
+impl From<String> for ValidatorLib {
+    fn from(name: String) -> Self {
+        match name.as_str() {
+            "yup" => ValidatorLib::Yup,
+            "zod" => ValidatorLib::Zod,
+            _ => ValidatorLib::Custom(name),
+        }
+    }
+}
+
+impl From<ValidatorLib> for String {
+    fn from(lib: ValidatorLib) -> Self {
+        lib.name().to_owned()
+    }
+}
+
+#[derive(Debug, PartialEq)]
 pub enum NumberRules {
     Min(f64),
     Max(f64),
+    /// Exclusive minimum
+    MoreThan(f64),
+    /// Exclusive maximum
+    LessThan(f64),
+    MultipleOf(f64),
+    Integer,
+    OneOf(Vec<f64>),
 }
 
 impl BuildableRule for NumberRules {
-    fn build(&self) -> String {
-        match self {
-            Self::Min(value) => format!(".min({})", value),
-            Self::Max(value) => format!(".max({})", value),
-        }
+    fn build(&self, emitter: &dyn ValidatorEmitter) -> String {
+        emitter.number_rule(self)
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub enum StringRules {
     Min(usize),
     Max(usize),
-    Matches(String),
+    Matches(JsPattern),
     OneOf(Vec<String>),
     Email,
     Uuid,
+    Url,
+    /// Full date, e.g. `2021-12-24`
+    Date,
+    /// RFC 3339 date-time, e.g. `2021-12-24T18:30:00Z`
+    DateTime,
+    Ipv4,
+    Ipv6,
+    Hostname,
 }
 
 impl BuildableRule for StringRules {
-    fn build(&self) -> String {
-        match self {
-            Self::Min(value) => format!(".min({})", value),
-            Self::Max(value) => format!(".max({})", value),
-            Self::Email => ".email()".to_string(),
-            /*
-                TODO: Handle special chars escaping
-            */
-            Self::Matches(regex) => format!(".matches(/{}/)", regex),
-            Self::Uuid => ".uuid()".to_string(),
-            Self::OneOf(enumerate) => {
-                let quoted: Vec<String> = enumerate.iter().map(|e| format!("`{}`", e)).collect();
-                format!(".oneOf([{}])", quoted.join(","))
-            }
-        }
+    fn build(&self, emitter: &dyn ValidatorEmitter) -> String {
+        emitter.string_rule(self)
+    }
+}
+
+#[derive(Debug)]
+pub enum ArrayRules {
+    Min(usize),
+    Max(usize),
+    Unique,
+}
+
+impl BuildableRule for ArrayRules {
+    fn build(&self, emitter: &dyn ValidatorEmitter) -> String {
+        emitter.array_rule(self)
     }
 }
 
@@ -69,170 +167,497 @@ impl BuildableRule for StringRules {
 pub enum PropRules {
     String(Vec<StringRules>),
     Number(Vec<NumberRules>),
+    Boolean,
+    /// `date-time` string converted to a `Date`
+    Date,
+    /// `int64` integer converted to a `bigint`
+    BigInt,
+    Reference(ModelRef),
+    Array(Box<PropRules>, Vec<ArrayRules>),
+    /// Rules of the values of an object of undeclared keys
+    Map(Box<PropRules>),
+    AllOf(Vec<PropRules>),
+    OneOf(Vec<PropRules>),
+    AnyOf(Vec<PropRules>),
+    /// Variant rules of every tag, dispatched on the value of the property
+    Discriminated(String, IndexMap<String, PropRules>),
     Unsupported,
 }
 
-impl PropRules {
-    /*
-        TODO: Refactor ProprRules with build trait
-    */
-    pub fn build(&self) -> String {
-        let ser_rules = match self {
-            PropRules::String(rules) => collect_rules(".string()", rules),
-            PropRules::Number(rules) => collect_rules(".number()", rules),
-            PropRules::Unsupported => String::from(".mixed()"),
-        };
+fn format_rule(format: &VariantOrUnknownOrEmpty<StringFormat>) -> Option<StringRules> {
+    let rule = match format {
+        VariantOrUnknownOrEmpty::Item(StringFormat::Date) => StringRules::Date,
+        VariantOrUnknownOrEmpty::Item(StringFormat::DateTime) => StringRules::DateTime,
+        VariantOrUnknownOrEmpty::Unknown(format) => match format.as_str() {
+            "email" => StringRules::Email,
+            "uuid" => StringRules::Uuid,
+            "uri" | "url" => StringRules::Url,
+            "ipv4" => StringRules::Ipv4,
+            "ipv6" => StringRules::Ipv6,
+            "hostname" => StringRules::Hostname,
+            _ => {
+                debug!("No validation rule for string format {}", format);
+                return None;
+            }
+        },
+        _ => return None,
+    };
+
+    Some(rule)
+}
+
+/// Pushes the `(bound, exclusive)` minimum and maximum of a number
+fn push_bounds(
+    rules: &mut Vec<NumberRules>,
+    minimum: (Option<f64>, bool),
+    maximum: (Option<f64>, bool),
+) {
+    match minimum {
+        (Some(min), true) => rules.push(NumberRules::MoreThan(min)),
+        (Some(min), false) => rules.push(NumberRules::Min(min)),
+        (None, _) => {}
+    }
 
-        format!("{}.required()", ser_rules)
+    match maximum {
+        (Some(max), true) => rules.push(NumberRules::LessThan(max)),
+        (Some(max), false) => rules.push(NumberRules::Max(max)),
+        (None, _) => {}
     }
 }
 
-#[derive(Debug)]
-pub struct ValidationGenerator {
-    pub name: String,
-    pub properties: IndexMap<String, PropRules>,
+fn members_rules(
+    members: &[PropType],
+    pointer: &str,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Vec<PropRules> {
+    members
+        .iter()
+        .enumerate()
+        .map(|(index, member)| type_rules(member, &format!("{}/{}", pointer, index), diagnostics))
+        .collect()
 }
 
-impl ValidationGenerator {
-    pub fn new(name: &str) -> Self {
-        Self {
-            name: name.to_owned(),
-            properties: IndexMap::new(),
+/// Rules of a property type, `pointer` locates it in the spec for the diagnostics
+pub(super) fn type_rules(
+    prop_type: &PropType,
+    pointer: &str,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> PropRules {
+    let prop_type = match prop_type {
+        PropType::Type(prop_type) => prop_type,
+        PropType::Reference(model_ref) => return PropRules::Reference(model_ref.clone()),
+        PropType::AllOf(members) => {
+            let pointer = format!("{}/allOf", pointer);
+            return PropRules::AllOf(members_rules(members, &pointer, diagnostics));
         }
-    }
+        PropType::OneOf(members) => {
+            let pointer = format!("{}/oneOf", pointer);
+            return PropRules::OneOf(members_rules(members, &pointer, diagnostics));
+        }
+        PropType::AnyOf(members) => {
+            let pointer = format!("{}/anyOf", pointer);
+            return PropRules::AnyOf(members_rules(members, &pointer, diagnostics));
+        }
+        PropType::Discriminated(union) => {
+            let variants = union
+                .variants
+                .iter()
+                .map(|(tag, model_ref)| (tag.clone(), PropRules::Reference(model_ref.clone())))
+                .collect();
+            return PropRules::Discriminated(union.property_name.clone(), variants);
+        }
+        PropType::Map(values) => {
+            let pointer = format!("{}/additionalProperties", pointer);
+            return PropRules::Map(Box::new(type_rules(values, &pointer, diagnostics)));
+        }
+        PropType::Date => return PropRules::Date,
+        PropType::BigInt => return PropRules::BigInt,
+        PropType::Any => return PropRules::Unsupported,
+        PropType::Array(array) => {
+            let mut rules = Vec::<ArrayRules>::new();
+
+            if let Some(min) = array.min_items {
+                rules.push(ArrayRules::Min(min));
+            }
 
-    pub fn register_property(&mut self, prop: &ModelProperty) {
-        let prop_rule = match &prop.prop_type {
-            Type::Number(number_type) => {
-                let mut rules = Vec::<NumberRules>::new();
+            if let Some(max) = array.max_items {
+                rules.push(ArrayRules::Max(max));
+            }
 
-                if let Some(min) = number_type.minimum {
-                    rules.push(NumberRules::Min(min));
-                }
+            if array.unique_items {
+                rules.push(ArrayRules::Unique);
+            }
 
-                if let Some(max) = number_type.maximum {
-                    rules.push(NumberRules::Max(max));
-                }
+            let items = type_rules(&array.items, &format!("{}/items", pointer), diagnostics);
+            return PropRules::Array(Box::new(items), rules);
+        }
+    };
+
+    match prop_type {
+        Type::Number(number_type) => {
+            let mut rules = Vec::<NumberRules>::new();
+            push_bounds(
+                &mut rules,
+                (number_type.minimum, number_type.exclusive_minimum),
+                (number_type.maximum, number_type.exclusive_maximum),
+            );
+
+            if let Some(multiple_of) = number_type.multiple_of {
+                rules.push(NumberRules::MultipleOf(multiple_of));
+            }
 
-                debug!("Number rules : {:#?}", rules);
-                PropRules::Number(rules)
+            if !number_type.enumeration.is_empty() {
+                rules.push(NumberRules::OneOf(number_type.enumeration.clone()));
             }
 
-            Type::String(string_rules) => {
-                let mut rules = Vec::<StringRules>::new();
+            debug!("Number rules : {:#?}", rules);
+            PropRules::Number(rules)
+        }
 
-                if let Some(min) = string_rules.min_length {
-                    rules.push(StringRules::Min(min));
-                }
+        Type::Integer(integer_type) => {
+            let mut rules = vec![NumberRules::Integer];
+            push_bounds(
+                &mut rules,
+                (
+                    integer_type.minimum.map(|min| min as f64),
+                    integer_type.exclusive_minimum,
+                ),
+                (
+                    integer_type.maximum.map(|max| max as f64),
+                    integer_type.exclusive_maximum,
+                ),
+            );
+
+            if let Some(multiple_of) = integer_type.multiple_of {
+                rules.push(NumberRules::MultipleOf(multiple_of as f64));
+            }
 
-                if let Some(max) = string_rules.max_length {
-                    rules.push(StringRules::Max(max));
-                }
+            if !integer_type.enumeration.is_empty() {
+                let enumeration = integer_type.enumeration.iter().map(|n| *n as f64);
+                rules.push(NumberRules::OneOf(enumeration.collect()));
+            }
 
-                if let Some(pattern) = &string_rules.pattern {
-                    rules.push(StringRules::Matches(pattern.clone()));
-                }
+            PropRules::Number(rules)
+        }
+
+        Type::String(string_rules) => {
+            let mut rules = Vec::<StringRules>::new();
+
+            if let Some(min) = string_rules.min_length {
+                rules.push(StringRules::Min(min));
+            }
+
+            if let Some(max) = string_rules.max_length {
+                rules.push(StringRules::Max(max));
+            }
 
-                if !string_rules.enumeration.is_empty() {
-                    rules.push(StringRules::OneOf(string_rules.enumeration.clone()));
+            if let Some(pattern) = &string_rules.pattern {
+                match JsPattern::translate(pattern) {
+                    Ok(pattern) => rules.push(StringRules::Matches(pattern)),
+                    Err(reason) => diagnostics.push(Diagnostic {
+                        pointer: pointer.to_owned(),
+                        message: format!("pattern {:?} is skipped, {}", pattern, reason),
+                    }),
                 }
+            }
+
+            if let Some(rule) = format_rule(&string_rules.format) {
+                rules.push(rule);
+            }
 
-                PropRules::String(rules)
+            if !string_rules.enumeration.is_empty() {
+                rules.push(StringRules::OneOf(string_rules.enumeration.clone()));
             }
-            _ => PropRules::Unsupported,
+
+            PropRules::String(rules)
+        }
+        Type::Boolean {} => PropRules::Boolean,
+        _ => PropRules::Unsupported,
+    }
+}
+
+#[derive(Debug)]
+pub struct PropValidation {
+    pub rules: PropRules,
+    pub required: bool,
+    pub nullable: bool,
+    pub default: Option<serde_json::Value>,
+}
+
+/// Issue found while generating code, located by the JSON pointer of its schema
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    pub pointer: String,
+    pub message: String,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.pointer, self.message)
+    }
+}
+
+#[derive(Debug)]
+pub struct ValidationGenerator {
+    pub name: String,
+    pub pointer: String,
+    pub properties: IndexMap<String, PropValidation>,
+    pub extends: Vec<String>,
+    pub alias: Option<PropRules>,
+    /// Set when the alias accepts `null`, as its interface does
+    pub nullable: bool,
+    /// Set when the validator references itself lazily, through a cycle of references
+    pub recursive: bool,
+    /// Rules of the undeclared properties, allowed besides the declared ones
+    pub additional_properties: Option<PropRules>,
+    /// Rejects undeclared properties
+    pub strict: bool,
+    /// Declaration style of the interface, a TypeScript `enum` needs a validator typed against it
+    pub enum_style: EnumStyle,
+    /// Constraints of the spec the validator can't express
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl ValidationGenerator {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+            pointer: schema_pointer(name),
+            properties: IndexMap::new(),
+            extends: vec![],
+            alias: None,
+            nullable: false,
+            recursive: false,
+            additional_properties: None,
+            strict: false,
+            enum_style: EnumStyle::default(),
+            diagnostics: vec![],
+        }
+    }
+
+    pub fn register_property(&mut self, prop: &ModelProperty) {
+        let validation = PropValidation {
+            rules: type_rules(
+                &prop.prop_type,
+                &format!(
+                    "{}/properties/{}",
+                    self.pointer,
+                    pointer_segment(&prop.name)
+                ),
+                &mut self.diagnostics,
+            ),
+            required: prop.required,
+            nullable: prop.data.nullable,
+            default: prop.data.default.clone(),
         };
 
-        self.properties.insert(prop.name.clone(), prop_rule);
+        self.properties.insert(prop.name.clone(), validation);
     }
 
     pub fn from(model: &Model) -> Self {
         let mut generator = ValidationGenerator::new(&model.name);
+        generator.pointer = model.pointer.clone();
         for (_, prop_type) in &model.properties {
             generator.register_property(prop_type);
         }
 
+        generator.extends = model
+            .extends
+            .iter()
+            .map(|model_ref| model_ref.name.clone())
+            .collect();
+        generator.recursive = model
+            .references()
+            .iter()
+            .any(|model_ref| model_ref.recursive);
+        generator.alias = model
+            .alias
+            .as_ref()
+            .map(|alias| type_rules(alias, &model.pointer, &mut generator.diagnostics));
+        // Tagged unions aren't nullable, their guards read the tag of the value
+        generator.nullable =
+            model.data.nullable && !matches!(model.alias, None | Some(PropType::Discriminated(_)));
+        let pointer = format!("{}/additionalProperties", model.pointer);
+        generator.additional_properties = model
+            .additional_properties
+            .as_ref()
+            .map(|values| type_rules(values, &pointer, &mut generator.diagnostics));
+        generator.strict = model.strict;
+
         generator
     }
 
-    pub fn build(&self) -> String {
-        let prop_shape: String = self
-            .properties
-            .iter()
-            .map(|(prop_name, prop_rules)| {
-                format!(
-                    "{prop_name}: yup{prop_rules},\n",
-                    prop_name = prop_name,
-                    prop_rules = prop_rules.build()
-                )
-            })
-            .collect();
+    /// Set when the validator checks a top-level `enum` schema
+    pub fn is_enumeration(&self) -> bool {
+        match &self.alias {
+            Some(PropRules::String(rules)) => rules
+                .iter()
+                .any(|rule| matches!(rule, StringRules::OneOf(_))),
+            Some(PropRules::Number(rules)) => rules
+                .iter()
+                .any(|rule| matches!(rule, NumberRules::OneOf(_))),
+            _ => false,
+        }
+    }
 
-        format!(
-            "export const {model_name}Validator = yup.object().shape({{ {serialized_shape} }});\n",
-            model_name = self.name,
-            serialized_shape = prop_shape
-        )
+    /// Set when the validator has to accept `null`, `enum` and `const` declarations never do
+    pub fn accepts_null(&self) -> bool {
+        self.nullable && (self.enum_style == EnumStyle::Union || !self.is_enumeration())
+    }
+
+    pub fn build(&self, emitter: &dyn ValidatorEmitter) -> String {
+        emitter.validator(self)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::codegen::StringRules;
+    use std::collections::HashMap;
 
-    use super::{NumberRules, PropRules};
+    use openapiv3::{IntegerType, StringFormat, StringType, Type, VariantOrUnknownOrEmpty};
 
-    #[test]
-    fn it_builds_number_rules() {
-        let rules = vec![NumberRules::Min(10.4), NumberRules::Max(40.0)];
-        let prop_rules = PropRules::Number(rules);
-        let built_rules = prop_rules.build();
-        assert_eq!(built_rules, ".number().min(10.4).max(40).required()");
-    }
+    use openapiv3::SchemaData;
 
-    #[test]
-    fn it_builds_string_match() {
-        let rules = vec![StringRules::Matches("^[A-Z]{3}$".to_string())];
-        let prop_rules = PropRules::String(rules);
-        let built_rules = prop_rules.build();
-        assert_eq!(built_rules, r#".string().matches(/^[A-Z]{3}$/).required()"#);
+    use crate::codegen::model::{ArrayProp, Model, ModelProperty, PropType};
+
+    use super::{
+        type_rules, Diagnostic, NumberRules, PropRules, StringRules, ValidationGenerator,
+        ValidatorEmitter, ValidatorLib,
+    };
+
+    fn string_format(format: VariantOrUnknownOrEmpty<StringFormat>) -> PropType {
+        PropType::Type(Type::String(StringType {
+            format,
+            ..StringType::default()
+        }))
     }
 
     #[test]
-    fn it_builds_string_len() {
-        let rules = vec![StringRules::Min(8), StringRules::Max(128)];
-        let prop_rules = PropRules::String(rules);
-        let built_rules = prop_rules.build();
-        assert_eq!(built_rules, ".string().min(8).max(128).required()");
+    fn it_maps_string_formats() {
+        let cases = [
+            ("email", StringRules::Email),
+            ("uuid", StringRules::Uuid),
+            ("uri", StringRules::Url),
+            ("ipv6", StringRules::Ipv6),
+            ("hostname", StringRules::Hostname),
+        ];
+        for (format, expected) in cases {
+            let rules = type_rules(
+                &string_format(VariantOrUnknownOrEmpty::Unknown(format.to_string())),
+                "#",
+                &mut vec![],
+            );
+            assert!(matches!(rules, PropRules::String(rules) if rules == [expected]));
+        }
+
+        let rules = type_rules(
+            &string_format(VariantOrUnknownOrEmpty::Item(StringFormat::DateTime)),
+            "#",
+            &mut vec![],
+        );
+        assert!(matches!(rules, PropRules::String(rules) if rules == [StringRules::DateTime]));
+
+        let rules = type_rules(
+            &string_format(VariantOrUnknownOrEmpty::Unknown(
+                "password-hash".to_string(),
+            )),
+            "#",
+            &mut vec![],
+        );
+        assert!(matches!(rules, PropRules::String(rules) if rules.is_empty()));
     }
 
     #[test]
-    fn it_builds_string_email() {
-        let rules = vec![StringRules::Email];
-        let prop_rules = PropRules::String(rules);
-        let built_rules = prop_rules.build();
-        assert_eq!(built_rules, ".string().email().required()");
+    fn it_resolves_validator_libs() {
+        let libs: Vec<ValidatorLib> = serde_json::from_str(r#"["yup", "zod", "valibot"]"#).unwrap();
+        assert_eq!(
+            libs,
+            [
+                ValidatorLib::Yup,
+                ValidatorLib::Zod,
+                ValidatorLib::Custom("valibot".to_string())
+            ]
+        );
+
+        let registered: HashMap<String, Box<dyn ValidatorEmitter>> = HashMap::new();
+        assert!(libs[1].emitter(&registered).is_some());
+        assert!(libs[2].emitter(&registered).is_none());
     }
 
     #[test]
-    fn it_builds_string_uuid() {
-        let rules = vec![StringRules::Uuid];
-        let prop_rules = PropRules::String(rules);
-        let built_rules = prop_rules.build();
-        assert_eq!(built_rules, ".string().uuid().required()");
+    fn it_escapes_property_pointers() {
+        let mut model = Model::new("Invoice", SchemaData::default());
+        model.properties.insert(
+            "lines/~total".to_string(),
+            ModelProperty {
+                name: "lines/~total".to_string(),
+                data: SchemaData::default(),
+                prop_type: PropType::Type(Type::String(StringType {
+                    pattern: Some(r"\GINV-\d+".to_string()),
+                    ..StringType::default()
+                })),
+                required: true,
+            },
+        );
+
+        let generator = ValidationGenerator::from(&model);
+        assert_eq!(
+            generator.diagnostics[0].pointer,
+            "#/components/schemas/Invoice/properties/lines~1~0total"
+        );
     }
 
     #[test]
-    fn it_builds_string_enums() {
-        let enum_of: Vec<String> = vec!["north", "west", "south", "est"]
-            .iter()
-            .map(|e| e.to_string())
-            .collect();
-        let rules = vec![StringRules::OneOf(enum_of)];
-        let prop_rules = PropRules::String(rules);
-        let built_rules = prop_rules.build();
+    fn it_reports_untranslatable_patterns() {
+        let mut model = Model::new("Invoice", SchemaData::default());
+        model.properties.insert(
+            "reference".to_string(),
+            ModelProperty {
+                name: "reference".to_string(),
+                data: SchemaData::default(),
+                prop_type: PropType::Array(ArrayProp {
+                    items: Box::new(PropType::Type(Type::String(StringType {
+                        pattern: Some(r"\GINV-\d+".to_string()),
+                        ..StringType::default()
+                    }))),
+                    min_items: None,
+                    max_items: None,
+                    unique_items: false,
+                }),
+                required: true,
+            },
+        );
+
+        let generator = ValidationGenerator::from(&model);
         assert_eq!(
-            built_rules,
-            r#".string().oneOf([`north`,`west`,`south`,`est`]).required()"#
+            generator.diagnostics,
+            [Diagnostic {
+                pointer: "#/components/schemas/Invoice/properties/reference/items".to_string(),
+                message: r#"pattern "\\GINV-\\d+" is skipped, \G has no JavaScript equivalent"#
+                    .to_string(),
+            }]
         );
     }
+
+    #[test]
+    fn it_maps_integer_constraints() {
+        let integer = PropType::Type(Type::Integer(IntegerType {
+            minimum: Some(0),
+            exclusive_minimum: true,
+            maximum: Some(100),
+            multiple_of: Some(5),
+            ..IntegerType::default()
+        }));
+
+        let rules = type_rules(&integer, "#", &mut vec![]);
+        assert!(matches!(
+            rules,
+            PropRules::Number(rules) if rules == [
+                NumberRules::Integer,
+                NumberRules::MoreThan(0.0),
+                NumberRules::Max(100.0),
+                NumberRules::MultipleOf(5.0),
+            ]
+        ));
+    }
 }
@@ -0,0 +1,726 @@
+use super::{
+    interface::serialize_prop_name,
+    pattern::{HOSTNAME_PATTERN, IPV4_OCTET},
+    validation::{
+        collect_rules, ArrayRules, NumberRules, PropRules, PropValidation, StringRules,
+        ValidationGenerator, ValidatorEmitter,
+    },
+};
+
+/// Default emitter, chains yup rules on the `yup` namespace or on referenced validators
+#[derive(Debug)]
+pub struct Yup;
+
+impl Yup {
+    /// Expression the built rules are chained onto
+    fn root(&self, rules: &PropRules) -> String {
+        if self.lazy_body(rules).is_some() {
+            return String::from("yup");
+        }
+
+        match rules {
+            PropRules::Reference(model_ref) => format!("{}Validator", model_ref.name),
+            PropRules::AllOf(members) => self.root(&members[0]),
+            _ => String::from("yup"),
+        }
+    }
+
+    /// Schema the `yup.lazy` callback of the rules resolves to, `None` when they aren't lazy.
+    /// Lazy schemas can't be concatenated nor made nullable.
+    fn lazy_body(&self, rules: &PropRules) -> Option<String> {
+        match rules {
+            // Not declared yet, yup has to resolve it lazily
+            PropRules::Reference(model_ref) if model_ref.recursive => {
+                Some(format!("{}Validator", model_ref.name))
+            }
+            PropRules::Map(values) => Some(self.entries(values)),
+            // The whole intersection is resolved lazily when one of its members is
+            PropRules::AllOf(members)
+                if members
+                    .iter()
+                    .any(|member| self.lazy_body(member).is_some()) =>
+            {
+                let resolved: Vec<String> = members
+                    .iter()
+                    .map(|member| {
+                        self.lazy_body(member)
+                            .unwrap_or_else(|| self.schema(member))
+                    })
+                    .collect();
+                let concatenated: String = resolved[1..]
+                    .iter()
+                    .map(|member| format!(".concat({})", member))
+                    .collect();
+                Some(format!("{}{}", resolved[0], concatenated))
+            }
+            _ => None,
+        }
+    }
+
+    /// Exported validator, self-referencing ones need a type TypeScript can't infer
+    fn declaration(&self, generator: &ValidationGenerator, schema: &str) -> String {
+        let annotation = if generator.recursive {
+            format!(": yup.Schema<{}>", generator.name)
+        } else {
+            String::new()
+        };
+
+        format!(
+            "export const {}Validator{} = {};\n",
+            generator.name, annotation, schema
+        )
+    }
+
+    /// Whether the lazy body reads the `value` the callback is given
+    fn reads_value(&self, rules: &PropRules) -> bool {
+        match rules {
+            PropRules::Map(_) => true,
+            PropRules::AllOf(members) => members.iter().any(|member| self.reads_value(member)),
+            _ => false,
+        }
+    }
+
+    /// Default, nullability and presence rules of a property
+    fn presence(&self, validation: &PropValidation) -> String {
+        let default = match &validation.default {
+            Some(default) => format!(".default({})", default),
+            // Objects default to `{}`, which would trigger the nested required rules
+            None if !validation.required
+                && matches!(
+                    validation.rules,
+                    PropRules::Reference(_) | PropRules::AllOf(_)
+                ) =>
+            {
+                String::from(".default(undefined)")
+            }
+            None => String::new(),
+        };
+
+        let presence = match (validation.required, validation.nullable) {
+            // `required` rejects null as well
+            (true, true) => ".nullable().defined()",
+            (true, false) => ".required()",
+            (false, true) => ".nullable().optional()",
+            (false, false) => ".optional()",
+        };
+
+        format!("{}{}", default, presence)
+    }
+
+    /// Object schema of the keys found on the value, which `yup.lazy` hands over
+    fn entries(&self, values: &PropRules) -> String {
+        format!(
+            "yup.object(Object.fromEntries(Object.keys(value ?? {{}}).map((key) => [key, {}])))",
+            self.schema(values)
+        )
+    }
+
+    fn build_with_presence(&self, rules: &PropRules, presence: &str) -> String {
+        if let Some(body) = self.lazy_body(rules) {
+            let args = if self.reads_value(rules) { "value" } else { "" };
+            return format!(".lazy(({}) => {}{})", args, body, presence);
+        }
+
+        let ser_rules = match rules {
+            PropRules::String(rules) => collect_rules(".string()", rules, self),
+            PropRules::Number(rules) => collect_rules(".number()", rules, self),
+            PropRules::Boolean => String::from(".boolean()"),
+            PropRules::Date => String::from(".date()"),
+            // yup has no bigint schema, raw numbers are converted and then checked for their type
+            PropRules::BigInt => String::from(
+                r#".mixed().transform((_, raw) => { try { return raw == null ? raw : BigInt(raw); } catch { return raw; } }).test("bigint", "${path} must be a bigint", (value) => value == null || typeof value === "bigint")"#,
+            ),
+            // References are the root of their rules, lazy rules are built above
+            PropRules::Reference(_) | PropRules::Map(_) => String::new(),
+            PropRules::Array(items, rules) => {
+                collect_rules(&format!(".array().of({})", self.schema(items)), rules, self)
+            }
+            PropRules::AllOf(members) => {
+                let concatenated: String = members[1..]
+                    .iter()
+                    .map(|member| format!(".concat({})", self.schema(member)))
+                    .collect();
+                format!(
+                    "{}{}",
+                    self.build_with_presence(&members[0], ""),
+                    concatenated
+                )
+            }
+            // Lazy schemas can't carry presence rules, unions are tested on a mixed schema instead
+            PropRules::OneOf(members) => format!(
+                r#".mixed().test("oneOf", "${{path}} must match exactly one schema", (value) => value == null || [{}].filter((schema) => schema.isValidSync(value)).length === 1)"#,
+                self.build_members(members)
+            ),
+            PropRules::Discriminated(property_name, variants) => {
+                let variants: Vec<String> = variants
+                    .iter()
+                    .map(|(tag, rules)| {
+                        format!(
+                            "{}: {}",
+                            serde_json::to_string(tag).unwrap(),
+                            self.schema(rules)
+                        )
+                    })
+                    .collect();
+
+                format!(
+                    r#".mixed().test("discriminator", "${{path}} must match the schema of its {property}", (value) => value == null || ({{ {variants} }})[value[{property_key}]]?.isValidSync(value) === true)"#,
+                    property = property_name,
+                    variants = variants.join(", "),
+                    property_key = serde_json::to_string(property_name).unwrap(),
+                )
+            }
+            PropRules::AnyOf(members) => format!(
+                r#".mixed().test("anyOf", "${{path}} must match at least one schema", (value) => value == null || [{}].some((schema) => schema.isValidSync(value)))"#,
+                self.build_members(members)
+            ),
+            PropRules::Unsupported => String::from(".mixed()"),
+        };
+
+        format!("{}{}", ser_rules, presence)
+    }
+
+    fn build_members(&self, members: &[PropRules]) -> String {
+        let members: Vec<String> = members.iter().map(|member| self.schema(member)).collect();
+        members.join(", ")
+    }
+}
+
+impl ValidatorEmitter for Yup {
+    fn import(&self) -> String {
+        String::from("import yup from \"yup\";")
+    }
+
+    fn number_rule(&self, rule: &NumberRules) -> String {
+        match rule {
+            NumberRules::Min(value) => format!(".min({})", value),
+            NumberRules::Max(value) => format!(".max({})", value),
+            NumberRules::MoreThan(value) => format!(".moreThan({})", value),
+            NumberRules::LessThan(value) => format!(".lessThan({})", value),
+            NumberRules::MultipleOf(value) if value.fract() == 0.0 => format!(
+                r#".test("multipleOf", "${{path}} must be a multiple of {value}", (value) => value == null || value % {value} === 0)"#,
+                value = value
+            ),
+            // Decimal steps aren't exact in binary, both sides are scaled to integers first
+            NumberRules::MultipleOf(value) => format!(
+                r#".test("multipleOf", "${{path}} must be a multiple of {value}", (value) => {{ if (value == null) return true; const decimals = Math.max((String(value).split(".")[1] ?? "").length, {decimals}); return Number(value.toFixed(decimals).replace(".", "")) % Number(({value}).toFixed(decimals).replace(".", "")) === 0; }})"#,
+                value = value,
+                decimals = value.to_string().split('.').nth(1).map_or(0, str::len)
+            ),
+            NumberRules::Integer => ".integer()".to_string(),
+            NumberRules::OneOf(values) => {
+                let values: Vec<String> = values.iter().map(f64::to_string).collect();
+                format!(".oneOf([{}])", values.join(","))
+            }
+        }
+    }
+
+    fn string_rule(&self, rule: &StringRules) -> String {
+        match rule {
+            StringRules::Min(value) => format!(".min({})", value),
+            StringRules::Max(value) => format!(".max({})", value),
+            StringRules::Email => ".email()".to_string(),
+            StringRules::Matches(pattern) => format!(".matches({})", pattern.expression()),
+            StringRules::Uuid => ".uuid()".to_string(),
+            StringRules::Url => ".url()".to_string(),
+            StringRules::Date => String::from(
+                r#".matches(/^\d{4}-\d{2}-\d{2}$/, "${path} must be a date (YYYY-MM-DD)")"#,
+            ),
+            StringRules::DateTime => String::from(
+                r#".test("date-time", "${path} must be an ISO 8601 date-time", (value) => value == null || !Number.isNaN(Date.parse(value)))"#,
+            ),
+            StringRules::Ipv4 => format!(
+                r#".matches(/^{octet}(\.{octet}){{3}}$/, "${{path}} must be an IPv4 address")"#,
+                octet = IPV4_OCTET
+            ),
+            StringRules::Ipv6 => String::from(
+                r#".test("ipv6", "${path} must be an IPv6 address", (value) => { if (value == null) return true; try { return new URL(`http://[${value}]`).hostname !== ""; } catch { return false; } })"#,
+            ),
+            StringRules::Hostname => format!(
+                r#".matches(/^{}$/, "${{path}} must be a hostname")"#,
+                HOSTNAME_PATTERN
+            ),
+            StringRules::OneOf(enumerate) => {
+                let quoted: Vec<String> = enumerate
+                    .iter()
+                    .map(|e| serde_json::to_string(e).unwrap())
+                    .collect();
+                format!(".oneOf([{}])", quoted.join(","))
+            }
+        }
+    }
+
+    fn array_rule(&self, rule: &ArrayRules) -> String {
+        match rule {
+            ArrayRules::Min(value) => format!(".min({})", value),
+            ArrayRules::Max(value) => format!(".max({})", value),
+            ArrayRules::Unique => String::from(
+                r#".test("unique", "${path} must only contain unique items", (items) => !items || new Set(items.map((item) => JSON.stringify(item))).size === items.length)"#,
+            ),
+        }
+    }
+
+    fn schema(&self, rules: &PropRules) -> String {
+        format!(
+            "{}{}",
+            self.root(rules),
+            self.build_with_presence(rules, "")
+        )
+    }
+
+    fn property(&self, validation: &PropValidation) -> String {
+        let rules = self.build_with_presence(&validation.rules, &self.presence(validation));
+
+        format!("{}{}", self.root(&validation.rules), rules)
+    }
+
+    fn response_validation(&self) -> Option<String> {
+        Some(String::from(
+            r#"function validateResponse<T>(operation: string, schema: yup.AnySchema, value: unknown): T {
+	try {
+		return schema.validateSync(value) as T;
+	} catch (error) {
+		if (error instanceof yup.ValidationError) {
+			throw new ResponseValidationError(operation, error.path ?? "", error.message, value);
+		}
+		throw error;
+	}
+}
+"#,
+        ))
+    }
+
+    fn validator(&self, generator: &ValidationGenerator) -> String {
+        if let Some(alias) = &generator.alias {
+            let schema = match self.lazy_body(alias) {
+                Some(body) if generator.accepts_null() => format!(
+                    "yup.lazy((value) => (value === null ? yup.mixed().nullable() : {}))",
+                    body
+                ),
+                None if generator.accepts_null() => format!("{}.nullable()", self.schema(alias)),
+                _ => self.schema(alias),
+            };
+
+            return self.declaration(generator, &schema);
+        }
+
+        let inherited: String = generator
+            .extends
+            .iter()
+            .map(|parent| format!("{}Validator.concat(", parent))
+            .collect();
+
+        let prop_shape: String = generator
+            .properties
+            .iter()
+            .map(|(prop_name, prop_validation)| {
+                format!(
+                    "{prop_name}: {prop_rules},\n",
+                    prop_name = serialize_prop_name(prop_name),
+                    prop_rules = self.property(prop_validation)
+                )
+            })
+            .collect();
+
+        let mut schema = format!(
+            "{inherited}yup.object().shape({{ {serialized_shape} }}){closing}",
+            inherited = inherited,
+            serialized_shape = prop_shape,
+            closing = ")".repeat(generator.extends.len())
+        );
+        if generator.strict {
+            schema.push_str(".noUnknown()");
+        }
+        // Declared properties are concatenated last, so their rules win over the entries ones
+        if let Some(values) = &generator.additional_properties {
+            schema = format!(
+                "yup.lazy((value) => {}.concat({}))",
+                self.entries(values),
+                schema
+            );
+        }
+
+        self.declaration(generator, &schema)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indexmap::IndexMap;
+    use openapiv3::{SchemaData, Type};
+
+    use crate::codegen::{
+        model::{Model, ModelProperty, ModelRef, PropType},
+        validation::{ArrayRules, NumberRules, PropRules, PropValidation},
+        JsPattern, StringRules, ValidationGenerator, ValidatorEmitter,
+    };
+
+    use super::Yup;
+
+    fn build(rules: &PropRules) -> String {
+        Yup.build_with_presence(rules, ".required()")
+    }
+
+    #[test]
+    fn it_builds_number_rules() {
+        let rules = vec![NumberRules::Min(10.4), NumberRules::Max(40.0)];
+        let prop_rules = PropRules::Number(rules);
+        let built_rules = build(&prop_rules);
+        assert_eq!(built_rules, ".number().min(10.4).max(40).required()");
+    }
+
+    #[test]
+    fn it_builds_integer_rules() {
+        let rules = vec![
+            NumberRules::Integer,
+            NumberRules::MoreThan(0.0),
+            NumberRules::LessThan(10.0),
+            NumberRules::MultipleOf(2.0),
+        ];
+        assert_eq!(
+            build(&PropRules::Number(rules)),
+            r#".number().integer().moreThan(0).lessThan(10).test("multipleOf", "${path} must be a multiple of 2", (value) => value == null || value % 2 === 0).required()"#
+        );
+
+        assert_eq!(
+            build(&PropRules::Number(vec![NumberRules::MultipleOf(0.01)])),
+            r#".number().test("multipleOf", "${path} must be a multiple of 0.01", (value) => { if (value == null) return true; const decimals = Math.max((String(value).split(".")[1] ?? "").length, 2); return Number(value.toFixed(decimals).replace(".", "")) % Number((0.01).toFixed(decimals).replace(".", "")) === 0; }).required()"#
+        );
+    }
+
+    #[test]
+    fn it_converts_bigints() {
+        assert_eq!(
+            build(&PropRules::BigInt),
+            r#".mixed().transform((_, raw) => { try { return raw == null ? raw : BigInt(raw); } catch { return raw; } }).test("bigint", "${path} must be a bigint", (value) => value == null || typeof value === "bigint").required()"#
+        );
+    }
+
+    #[test]
+    fn it_builds_string_match() {
+        let rules = vec![StringRules::Matches(JsPattern::new("^[A-Z]{3}$"))];
+        let prop_rules = PropRules::String(rules);
+        let built_rules = build(&prop_rules);
+        assert_eq!(built_rules, r#".string().matches(/^[A-Z]{3}$/).required()"#);
+    }
+
+    #[test]
+    fn it_builds_string_len() {
+        let rules = vec![StringRules::Min(8), StringRules::Max(128)];
+        let prop_rules = PropRules::String(rules);
+        let built_rules = build(&prop_rules);
+        assert_eq!(built_rules, ".string().min(8).max(128).required()");
+    }
+
+    #[test]
+    fn it_builds_string_email() {
+        let rules = vec![StringRules::Email];
+        let prop_rules = PropRules::String(rules);
+        let built_rules = build(&prop_rules);
+        assert_eq!(built_rules, ".string().email().required()");
+    }
+
+    #[test]
+    fn it_builds_string_uuid() {
+        let rules = vec![StringRules::Uuid];
+        let prop_rules = PropRules::String(rules);
+        let built_rules = build(&prop_rules);
+        assert_eq!(built_rules, ".string().uuid().required()");
+    }
+
+    #[test]
+    fn it_builds_string_formats() {
+        let rules = vec![StringRules::Url, StringRules::Ipv4];
+        assert_eq!(
+            build(&PropRules::String(rules)),
+            r#".string().url().matches(/^(25[0-5]|2[0-4]\d|1?\d?\d)(\.(25[0-5]|2[0-4]\d|1?\d?\d)){3}$/, "${path} must be an IPv4 address").required()"#
+        );
+        assert_eq!(Yup.schema(&PropRules::Date), "yup.date()");
+    }
+
+    #[test]
+    fn it_builds_string_enums() {
+        let enum_of: Vec<String> = ["north", "west", "south", "`${est}`"]
+            .iter()
+            .map(|e| e.to_string())
+            .collect();
+        let rules = vec![StringRules::OneOf(enum_of)];
+        let prop_rules = PropRules::String(rules);
+        let built_rules = build(&prop_rules);
+        assert_eq!(
+            built_rules,
+            r#".string().oneOf(["north","west","south","`${est}`"]).required()"#
+        );
+    }
+
+    #[test]
+    fn it_builds_boolean() {
+        assert_eq!(build(&PropRules::Boolean), ".boolean().required()");
+    }
+
+    #[test]
+    fn it_builds_reference() {
+        let prop_rules = PropRules::Reference(ModelRef::new("BidderDto"));
+        assert_eq!(Yup.root(&prop_rules), "BidderDtoValidator");
+        assert_eq!(build(&prop_rules), ".required()");
+    }
+
+    #[test]
+    fn it_builds_recursive_reference_lazily() {
+        let prop_rules = PropRules::Reference(ModelRef {
+            name: "TreeNode".to_string(),
+            recursive: true,
+        });
+        assert_eq!(Yup.root(&prop_rules), "yup");
+        assert_eq!(
+            build(&prop_rules),
+            ".lazy(() => TreeNodeValidator.required())"
+        );
+    }
+
+    #[test]
+    fn it_builds_array_rules() {
+        let items = PropRules::String(vec![StringRules::Email]);
+        let rules = vec![ArrayRules::Min(1), ArrayRules::Max(5)];
+        let prop_rules = PropRules::Array(Box::new(items), rules);
+        assert_eq!(
+            build(&prop_rules),
+            ".array().of(yup.string().email()).min(1).max(5).required()"
+        );
+    }
+
+    #[test]
+    fn it_builds_nested_array_of_references() {
+        let bids = PropRules::Array(
+            Box::new(PropRules::Reference(ModelRef::new("BidDto"))),
+            vec![],
+        );
+        let prop_rules = PropRules::Array(Box::new(bids), vec![ArrayRules::Unique]);
+        assert_eq!(
+            build(&prop_rules),
+            r#".array().of(yup.array().of(BidDtoValidator)).test("unique", "${path} must only contain unique items", (items) => !items || new Set(items.map((item) => JSON.stringify(item))).size === items.length).required()"#
+        );
+    }
+
+    #[test]
+    fn it_builds_optional_rules() {
+        let optional_number = PropValidation {
+            rules: PropRules::Number(vec![NumberRules::Min(0.0)]),
+            required: false,
+            nullable: false,
+            default: None,
+        };
+        assert_eq!(
+            Yup.property(&optional_number),
+            "yup.number().min(0).optional()"
+        );
+
+        let optional_reference = PropValidation {
+            rules: PropRules::Reference(ModelRef::new("BidderDto")),
+            required: false,
+            nullable: false,
+            default: None,
+        };
+        assert_eq!(
+            Yup.property(&optional_reference),
+            "BidderDtoValidator.default(undefined).optional()"
+        );
+
+        let optional_recursive = PropValidation {
+            rules: PropRules::Reference(ModelRef {
+                name: "TreeNode".to_string(),
+                recursive: true,
+            }),
+            required: false,
+            nullable: false,
+            default: None,
+        };
+        assert_eq!(
+            Yup.property(&optional_recursive),
+            "yup.lazy(() => TreeNodeValidator.default(undefined).optional())"
+        );
+    }
+
+    #[test]
+    fn it_builds_nullable_and_default_rules() {
+        let nullable = PropValidation {
+            rules: PropRules::String(vec![]),
+            required: true,
+            nullable: true,
+            default: None,
+        };
+        assert_eq!(Yup.property(&nullable), "yup.string().nullable().defined()");
+
+        let defaulted = PropValidation {
+            rules: PropRules::Reference(ModelRef::new("Theme")),
+            required: false,
+            nullable: true,
+            default: Some(serde_json::json!({ "dark": true })),
+        };
+        assert_eq!(
+            Yup.property(&defaulted),
+            r#"ThemeValidator.default({"dark":true}).nullable().optional()"#
+        );
+    }
+
+    #[test]
+    fn it_builds_compositions() {
+        let references = || {
+            vec![
+                PropRules::Reference(ModelRef::new("Cat")),
+                PropRules::Reference(ModelRef::new("Dog")),
+            ]
+        };
+
+        assert_eq!(
+            Yup.schema(&PropRules::AllOf(references())),
+            "CatValidator.concat(DogValidator)"
+        );
+        assert_eq!(
+            build(&PropRules::OneOf(references())),
+            r#".mixed().test("oneOf", "${path} must match exactly one schema", (value) => value == null || [CatValidator, DogValidator].filter((schema) => schema.isValidSync(value)).length === 1).required()"#
+        );
+        assert_eq!(
+            build(&PropRules::AnyOf(references())),
+            r#".mixed().test("anyOf", "${path} must match at least one schema", (value) => value == null || [CatValidator, DogValidator].some((schema) => schema.isValidSync(value))).required()"#
+        );
+    }
+
+    #[test]
+    fn it_builds_lazy_compositions() {
+        let mut tree = ModelRef::new("Tree");
+        tree.recursive = true;
+        let members = vec![
+            PropRules::Reference(tree),
+            PropRules::Reference(ModelRef::new("Labelled")),
+        ];
+
+        assert_eq!(
+            Yup.schema(&PropRules::AllOf(members)),
+            "yup.lazy(() => TreeValidator.concat(LabelledValidator))"
+        );
+    }
+
+    #[test]
+    fn it_annotates_self_referencing_validators() {
+        let mut parent = ModelRef::new("Category");
+        parent.recursive = true;
+        let mut model = Model::new("Category", SchemaData::default());
+        model.properties.insert(
+            "parent".to_string(),
+            ModelProperty {
+                name: "parent".to_string(),
+                data: SchemaData::default(),
+                prop_type: PropType::Reference(parent),
+                required: false,
+            },
+        );
+
+        assert!(ValidationGenerator::from(&model)
+            .build(&Yup)
+            .starts_with("export const CategoryValidator: yup.Schema<Category> = yup.object()"));
+    }
+
+    #[test]
+    fn it_quotes_non_identifier_keys() {
+        let mut model = Model::new("Trace", SchemaData::default());
+        model.properties.insert(
+            "x-id".to_string(),
+            ModelProperty {
+                name: "x-id".to_string(),
+                data: SchemaData::default(),
+                prop_type: PropType::Type(Type::Boolean {}),
+                required: true,
+            },
+        );
+
+        assert_eq!(
+            ValidationGenerator::from(&model).build(&Yup),
+            "export const TraceValidator = yup.object().shape({ \"x-id\": yup.boolean().required(),\n });\n"
+        );
+    }
+
+    #[test]
+    fn it_builds_inherited_validator() {
+        let mut model = Model::new("Dog", SchemaData::default());
+        model.extends.push(ModelRef::new("Pet"));
+
+        assert_eq!(
+            ValidationGenerator::from(&model).build(&Yup),
+            "export const DogValidator = PetValidator.concat(yup.object().shape({  }));\n"
+        );
+    }
+
+    #[test]
+    fn it_builds_additional_properties() {
+        let mut generator = ValidationGenerator::new("Settings");
+        generator.additional_properties = Some(PropRules::Number(vec![]));
+        assert_eq!(
+            generator.build(&Yup),
+            "export const SettingsValidator = yup.lazy((value) => yup.object(Object.fromEntries(Object.keys(value ?? {}).map((key) => [key, yup.number()]))).concat(yup.object().shape({  })));\n"
+        );
+
+        let mut generator = ValidationGenerator::new("Settings");
+        generator.strict = true;
+        assert_eq!(
+            generator.build(&Yup),
+            "export const SettingsValidator = yup.object().shape({  }).noUnknown();\n"
+        );
+
+        assert_eq!(
+            build(&PropRules::Map(Box::new(PropRules::Boolean))),
+            ".lazy((value) => yup.object(Object.fromEntries(Object.keys(value ?? {}).map((key) => [key, yup.boolean()]))).required())"
+        );
+    }
+
+    #[test]
+    fn it_builds_nullable_alias_validators() {
+        let mut model = Model::new("Owner", SchemaData::default());
+        model.data.nullable = true;
+        model.alias = Some(PropType::Reference(ModelRef::new("Person")));
+        assert_eq!(
+            ValidationGenerator::from(&model).build(&Yup),
+            "export const OwnerValidator = PersonValidator.nullable();\n"
+        );
+
+        let mut tree = ModelRef::new("Tree");
+        tree.recursive = true;
+        model.alias = Some(PropType::Reference(tree));
+        assert_eq!(
+            ValidationGenerator::from(&model).build(&Yup),
+            "export const OwnerValidator: yup.Schema<Owner> = yup.lazy((value) => (value === null ? yup.mixed().nullable() : TreeValidator));\n"
+        );
+    }
+
+    #[test]
+    fn it_builds_alias_validator() {
+        let mut model = Model::new("Pet", SchemaData::default());
+        model.alias = Some(PropType::AnyOf(vec![
+            PropType::Reference(ModelRef::new("Cat")),
+            PropType::Reference(ModelRef::new("Dog")),
+        ]));
+
+        assert_eq!(
+            ValidationGenerator::from(&model).build(&Yup),
+            "export const PetValidator = yup.mixed().test(\"anyOf\", \"${path} must match at least one schema\", (value) => value == null || [CatValidator, DogValidator].some((schema) => schema.isValidSync(value)));\n"
+        );
+    }
+
+    #[test]
+    fn it_builds_discriminated_rules() {
+        let mut variants = IndexMap::new();
+        variants.insert(
+            "bid_placed".to_string(),
+            PropRules::Reference(ModelRef::new("BidPlaced")),
+        );
+        variants.insert(
+            "ended".to_string(),
+            PropRules::Reference(ModelRef::new("AuctionEnded")),
+        );
+
+        assert_eq!(
+            Yup.schema(&PropRules::Discriminated("type".to_string(), variants)),
+            r#"yup.mixed().test("discriminator", "${path} must match the schema of its type", (value) => value == null || ({ "bid_placed": BidPlacedValidator, "ended": AuctionEndedValidator })[value["type"]]?.isValidSync(value) === true)"#
+        );
+    }
+}
@@ -0,0 +1,431 @@
+use super::{
+    interface::{serialize_prop_name, EnumStyle},
+    pattern::HOSTNAME_PATTERN,
+    validation::{
+        ArrayRules, NumberRules, PropRules, PropValidation, StringRules, ValidationGenerator,
+        ValidatorEmitter,
+    },
+};
+
+/// Emits zod schemas, picked with `"validator": "zod"` in the output config
+#[derive(Debug)]
+pub struct Zod;
+
+impl Zod {
+    /// `z.enum` keeps the literal types, the length rules are implied by the enumeration
+    fn build_string(&self, rules: &[StringRules]) -> String {
+        for rule in rules {
+            if let StringRules::OneOf(enumerate) = rule {
+                let quoted: Vec<String> = enumerate
+                    .iter()
+                    .map(|e| serde_json::to_string(e).unwrap())
+                    .collect();
+                return format!("z.enum([{}])", quoted.join(", "));
+            }
+        }
+
+        let collected_rules: String = rules.iter().map(|rule| self.string_rule(rule)).collect();
+        format!("z.string(){}", collected_rules)
+    }
+
+    /// Literals keep the enumerated values in the inferred type, like `z.enum` for strings
+    fn build_number(&self, rules: &[NumberRules]) -> String {
+        for rule in rules {
+            if let NumberRules::OneOf(values) = rule {
+                let literals: Vec<String> = values
+                    .iter()
+                    .map(|value| format!("z.literal({})", value))
+                    .collect();
+                return Zod::build_union(&literals);
+            }
+        }
+
+        let collected_rules: String = rules.iter().map(|rule| self.number_rule(rule)).collect();
+        format!("z.number(){}", collected_rules)
+    }
+
+    /// `z.union` needs at least two members
+    fn build_union(members: &[String]) -> String {
+        match members {
+            [member] => member.clone(),
+            _ => format!("z.union([{}])", members.join(", ")),
+        }
+    }
+}
+
+impl ValidatorEmitter for Zod {
+    fn import(&self) -> String {
+        String::from("import { z } from \"zod\";")
+    }
+
+    fn number_rule(&self, rule: &NumberRules) -> String {
+        match rule {
+            NumberRules::Min(value) => format!(".min({})", value),
+            NumberRules::Max(value) => format!(".max({})", value),
+            NumberRules::MoreThan(value) => format!(".gt({})", value),
+            NumberRules::LessThan(value) => format!(".lt({})", value),
+            NumberRules::MultipleOf(value) => format!(".multipleOf({})", value),
+            NumberRules::Integer => ".int()".to_string(),
+            // Enumerations replace the whole number schema, see `build_number`
+            NumberRules::OneOf(_) => String::new(),
+        }
+    }
+
+    fn string_rule(&self, rule: &StringRules) -> String {
+        match rule {
+            StringRules::Min(value) => format!(".min({})", value),
+            StringRules::Max(value) => format!(".max({})", value),
+            StringRules::Email => ".email()".to_string(),
+            StringRules::Matches(pattern) => format!(".regex({})", pattern.expression()),
+            StringRules::Uuid => ".uuid()".to_string(),
+            StringRules::Url => ".url()".to_string(),
+            StringRules::Date => ".date()".to_string(),
+            StringRules::DateTime => ".datetime({ offset: true })".to_string(),
+            StringRules::Ipv4 => r#".ip({ version: "v4" })"#.to_string(),
+            StringRules::Ipv6 => r#".ip({ version: "v6" })"#.to_string(),
+            StringRules::Hostname => format!(".regex(/^{}$/)", HOSTNAME_PATTERN),
+            // Enumerations replace the whole string schema, see `build_string`
+            StringRules::OneOf(_) => String::new(),
+        }
+    }
+
+    fn array_rule(&self, rule: &ArrayRules) -> String {
+        match rule {
+            ArrayRules::Min(value) => format!(".min({})", value),
+            ArrayRules::Max(value) => format!(".max({})", value),
+            ArrayRules::Unique => String::from(
+                r#".refine((items) => new Set(items.map((item) => JSON.stringify(item))).size === items.length, { message: "Must only contain unique items" })"#,
+            ),
+        }
+    }
+
+    fn schema(&self, rules: &PropRules) -> String {
+        match rules {
+            PropRules::String(rules) => self.build_string(rules),
+            PropRules::Number(rules) => self.build_number(rules),
+            PropRules::Boolean => String::from("z.boolean()"),
+            PropRules::Date => String::from("z.coerce.date()"),
+            PropRules::BigInt => String::from("z.coerce.bigint()"),
+            // Not declared yet, zod has to resolve it lazily
+            PropRules::Reference(model_ref) if model_ref.recursive => {
+                format!("z.lazy(() => {}Validator)", model_ref.name)
+            }
+            PropRules::Reference(model_ref) => format!("{}Validator", model_ref.name),
+            PropRules::Array(items, rules) => {
+                let collected_rules: String =
+                    rules.iter().map(|rule| self.array_rule(rule)).collect();
+                format!("z.array({}){}", self.schema(items), collected_rules)
+            }
+            PropRules::Map(values) => format!("z.record({})", self.schema(values)),
+            PropRules::AllOf(members) => {
+                let intersected: String = members[1..]
+                    .iter()
+                    .map(|member| format!(".and({})", self.schema(member)))
+                    .collect();
+                format!("{}{}", self.schema(&members[0]), intersected)
+            }
+            PropRules::OneOf(members) => {
+                let members: Vec<String> =
+                    members.iter().map(|member| self.schema(member)).collect();
+                format!(
+                    r#"{}.refine((value) => [{}].filter((schema) => schema.safeParse(value).success).length === 1, {{ message: "Must match exactly one schema" }})"#,
+                    Zod::build_union(&members),
+                    members.join(", ")
+                )
+            }
+            PropRules::AnyOf(members) => {
+                let members: Vec<String> =
+                    members.iter().map(|member| self.schema(member)).collect();
+                Zod::build_union(&members)
+            }
+            // Mirrors the `(Variant & { tag: "value" })` members of the interface
+            PropRules::Discriminated(property_name, variants) => {
+                let variants: Vec<String> = variants
+                    .iter()
+                    .map(|(tag, rules)| {
+                        format!(
+                            "{}.and(z.object({{ {}: z.literal({}) }}))",
+                            self.schema(rules),
+                            serialize_prop_name(property_name),
+                            serde_json::to_string(tag).unwrap()
+                        )
+                    })
+                    .collect();
+                Zod::build_union(&variants)
+            }
+            PropRules::Unsupported => String::from("z.unknown()"),
+        }
+    }
+
+    fn property(&self, validation: &PropValidation) -> String {
+        let mut schema = self.schema(&validation.rules);
+        if validation.nullable {
+            schema.push_str(".nullable()");
+        }
+        if !validation.required {
+            schema.push_str(".optional()");
+        }
+        if let Some(default) = &validation.default {
+            schema.push_str(&format!(".default({})", default));
+        }
+
+        schema
+    }
+
+    fn response_validation(&self) -> Option<String> {
+        Some(String::from(
+            r#"function validateResponse<T>(operation: string, schema: z.ZodTypeAny, value: unknown): T {
+	const result = schema.safeParse(value);
+	if (!result.success) {
+		const issue = result.error.issues[0];
+		throw new ResponseValidationError(operation, issue.path.join("."), issue.message, value);
+	}
+	return result.data as T;
+}
+"#,
+        ))
+    }
+
+    /// Validators are typed against the generated interface, so `z.infer<typeof FooValidator>` is `Foo`
+    fn validator(&self, generator: &ValidationGenerator) -> String {
+        let schema = match &generator.alias {
+            // `z.enum` infers a union of literals, which a TypeScript `enum` isn't assignable from
+            Some(_) if generator.is_enumeration() && generator.enum_style == EnumStyle::Enum => {
+                format!("z.nativeEnum({})", generator.name)
+            }
+            Some(alias) if generator.accepts_null() => format!("{}.nullable()", self.schema(alias)),
+            Some(alias) => self.schema(alias),
+            None => {
+                let inherited: String = generator
+                    .extends
+                    .iter()
+                    .map(|parent| format!("{}Validator.and(", parent))
+                    .collect();
+
+                let prop_shape: String = generator
+                    .properties
+                    .iter()
+                    .map(|(prop_name, prop_validation)| {
+                        format!(
+                            "{}: {},\n",
+                            serialize_prop_name(prop_name),
+                            self.property(prop_validation)
+                        )
+                    })
+                    .collect();
+
+                // An intersection checks every key against both sides, so the undeclared
+                // properties are only checked on models without parents
+                let mut object = format!("z.object({{ {} }})", prop_shape);
+                if generator.extends.is_empty() {
+                    if let Some(values) = &generator.additional_properties {
+                        object.push_str(&format!(".catchall({})", self.schema(values)));
+                    }
+                    if generator.strict {
+                        object.push_str(".strict()");
+                    }
+                }
+
+                format!(
+                    "{}{}{}",
+                    inherited,
+                    object,
+                    ")".repeat(generator.extends.len())
+                )
+            }
+        };
+
+        format!(
+            "export const {model_name}Validator: z.ZodType<{model_name}> = {schema};\n",
+            model_name = generator.name,
+            schema = schema
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indexmap::IndexMap;
+    use openapiv3::SchemaData;
+
+    use crate::codegen::{
+        model::{Model, ModelProperty, ModelRef, PropType},
+        validation::{ArrayRules, NumberRules, PropRules, PropValidation, StringRules},
+        EnumStyle, JsPattern, ValidationGenerator, ValidatorEmitter,
+    };
+
+    use super::Zod;
+
+    #[test]
+    fn it_builds_zod_primitives() {
+        assert_eq!(
+            Zod.schema(&PropRules::String(vec![
+                StringRules::Min(8),
+                StringRules::Max(128),
+                StringRules::Matches(JsPattern::new("^[A-Z]{3}$")),
+            ])),
+            "z.string().min(8).max(128).regex(/^[A-Z]{3}$/)"
+        );
+        assert_eq!(
+            Zod.schema(&PropRules::String(vec![StringRules::OneOf(vec![
+                "north".to_string(),
+                "south".to_string()
+            ])])),
+            r#"z.enum(["north", "south"])"#
+        );
+        assert_eq!(
+            Zod.schema(&PropRules::Number(vec![
+                NumberRules::Integer,
+                NumberRules::Min(0.0),
+                NumberRules::LessThan(10.5),
+                NumberRules::MultipleOf(3.0)
+            ])),
+            "z.number().int().min(0).lt(10.5).multipleOf(3)"
+        );
+        assert_eq!(
+            Zod.schema(&PropRules::String(vec![
+                StringRules::Email,
+                StringRules::DateTime
+            ])),
+            "z.string().email().datetime({ offset: true })"
+        );
+        assert_eq!(Zod.schema(&PropRules::Date), "z.coerce.date()");
+        assert_eq!(
+            Zod.schema(&PropRules::Number(vec![
+                NumberRules::Integer,
+                NumberRules::OneOf(vec![1.0, 2.0])
+            ])),
+            "z.union([z.literal(1), z.literal(2)])"
+        );
+    }
+
+    #[test]
+    fn it_builds_zod_nullable_aliases() {
+        let mut generator = ValidationGenerator::new("Nickname");
+        generator.alias = Some(PropRules::String(vec![]));
+        generator.nullable = true;
+
+        assert_eq!(
+            generator.build(&Zod),
+            "export const NicknameValidator: z.ZodType<Nickname> = z.string().nullable();\n"
+        );
+    }
+
+    #[test]
+    fn it_builds_zod_native_enums() {
+        let mut generator = ValidationGenerator::new("Priority");
+        generator.alias = Some(PropRules::Number(vec![NumberRules::OneOf(vec![1.0, 2.0])]));
+        generator.enum_style = EnumStyle::Enum;
+
+        assert_eq!(
+            generator.build(&Zod),
+            "export const PriorityValidator: z.ZodType<Priority> = z.nativeEnum(Priority);\n"
+        );
+    }
+
+    #[test]
+    fn it_builds_zod_arrays_and_references() {
+        let items = PropRules::Reference(ModelRef::new("BidDto"));
+        assert_eq!(
+            Zod.schema(&PropRules::Array(Box::new(items), vec![ArrayRules::Min(1)])),
+            "z.array(BidDtoValidator).min(1)"
+        );
+        assert_eq!(
+            Zod.schema(&PropRules::Reference(ModelRef {
+                name: "TreeNode".to_string(),
+                recursive: true,
+            })),
+            "z.lazy(() => TreeNodeValidator)"
+        );
+    }
+
+    #[test]
+    fn it_builds_zod_compositions() {
+        let references = || {
+            vec![
+                PropRules::Reference(ModelRef::new("Cat")),
+                PropRules::Reference(ModelRef::new("Dog")),
+            ]
+        };
+
+        assert_eq!(
+            Zod.schema(&PropRules::AllOf(references())),
+            "CatValidator.and(DogValidator)"
+        );
+        assert_eq!(
+            Zod.schema(&PropRules::AnyOf(references())),
+            "z.union([CatValidator, DogValidator])"
+        );
+
+        let mut variants = IndexMap::new();
+        variants.insert(
+            "cat".to_string(),
+            PropRules::Reference(ModelRef::new("Cat")),
+        );
+        variants.insert(
+            "dog".to_string(),
+            PropRules::Reference(ModelRef::new("Dog")),
+        );
+        assert_eq!(
+            Zod.schema(&PropRules::Discriminated("kind".to_string(), variants)),
+            r#"z.union([CatValidator.and(z.object({ kind: z.literal("cat") })), DogValidator.and(z.object({ kind: z.literal("dog") }))])"#
+        );
+    }
+
+    #[test]
+    fn it_builds_zod_nullable_and_default() {
+        let validation = PropValidation {
+            rules: PropRules::Number(vec![]),
+            required: false,
+            nullable: true,
+            default: Some(serde_json::json!(3)),
+        };
+
+        assert_eq!(
+            Zod.property(&validation),
+            "z.number().nullable().optional().default(3)"
+        );
+    }
+
+    #[test]
+    fn it_builds_zod_additional_properties() {
+        assert_eq!(
+            Zod.schema(&PropRules::Map(Box::new(PropRules::Boolean))),
+            "z.record(z.boolean())"
+        );
+
+        let mut generator = ValidationGenerator::new("Settings");
+        generator.additional_properties = Some(PropRules::Unsupported);
+        assert_eq!(
+            generator.build(&Zod),
+            "export const SettingsValidator: z.ZodType<Settings> = z.object({  }).catchall(z.unknown());\n"
+        );
+
+        generator.additional_properties = None;
+        generator.strict = true;
+        assert_eq!(
+            generator.build(&Zod),
+            "export const SettingsValidator: z.ZodType<Settings> = z.object({  }).strict();\n"
+        );
+    }
+
+    #[test]
+    fn it_builds_zod_validator() {
+        let mut model = Model::new("Dog", SchemaData::default());
+        model.extends.push(ModelRef::new("Pet"));
+        model.properties.insert(
+            "nick-name".to_string(),
+            ModelProperty {
+                name: "nick-name".to_string(),
+                data: SchemaData::default(),
+                prop_type: PropType::Any,
+                required: false,
+            },
+        );
+
+        assert_eq!(
+            ValidationGenerator::from(&model).build(&Zod),
+            "export const DogValidator: z.ZodType<Dog> = PetValidator.and(z.object({ \"nick-name\": z.unknown().optional(),\n }));\n"
+        );
+    }
+}
@@ -2,6 +2,11 @@ use std::{collections::HashMap, io};
 
 use log::debug;
 
+use crate::{
+    codegen::{ClientLib, EnumStyle, HookLib, ValidatorLib},
+    error::SahihError,
+};
+
 #[derive(Serialize, Deserialize, Debug, PartialEq)]
 pub struct SahihConfig {
     pub output: SahihOutputConfig,
@@ -12,13 +17,54 @@ pub struct SahihOutputConfig {
     pub target: String,
     #[serde(default)]
     pub overwrite: bool,
+    /// Library the validators are generated for, `yup` or `zod`
+    #[serde(default)]
+    pub validator: ValidatorLib,
+    /// Type `date-time` strings as `Date`, the client converts the responses by validating them
+    /// as `validate_responses` does
+    #[serde(default)]
+    pub date_time_as_date: bool,
+    /// Type `int64` integers as `bigint`, the client converts the responses by validating them
+    /// as `validate_responses` does
+    #[serde(default)]
+    pub int64_as_bigint: bool,
+    /// Export a `FooDefaults` object with the declared defaults of every model
+    #[serde(default)]
+    pub defaults: bool,
+    /// Declaration of top-level `enum` schemas, `union`, `enum` or `const`
+    #[serde(default)]
+    pub enum_style: EnumStyle,
+    /// Derive a `FooInput` model without the `readOnly` properties and a `FooOutput` model
+    /// without the `writeOnly` ones, only the inputs get a validator
+    #[serde(default)]
+    pub input_output_variants: bool,
+    /// Library of the generated `hooks.ts`, `react-query` or `swr`, no hooks are generated when unset
+    #[serde(default)]
+    pub hooks: Option<HookLib>,
+    /// Flavour of the generated `client.ts`, `fetch` functions or an `axios` class
+    #[serde(default)]
+    pub client: ClientLib,
+    /// Check responses with the model validators, throwing a `ResponseValidationError` on mismatch
+    #[serde(default)]
+    pub validate_responses: bool,
+}
+
+fn default_timeout() -> u64 {
+    30
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq)]
 pub struct SahihInputConfig {
+    /// Path of the spec, or its URL when `from_url` is set
     pub target: String,
     #[serde(default)]
     pub from_url: bool,
+    /// Extra request headers sent when fetching from a URL, e.g. `Authorization`
+    #[serde(default)]
+    pub headers: HashMap<String, String>,
+    /// Timeout in seconds when fetching from a URL
+    #[serde(default = "default_timeout")]
+    pub timeout: u64,
 }
 
 pub trait ConfigManagement {
@@ -32,14 +78,16 @@ pub struct ConfigManager {
 }
 
 impl ConfigManager {
-    pub fn from(path: &str) -> Self {
+    pub fn from(path: &str) -> Result<Self, SahihError> {
         debug!("Reading config from {}", path);
-        let raw_config = std::fs::read_to_string(path).expect("Could not read file");
+        let raw_config =
+            std::fs::read_to_string(path).map_err(|error| SahihError::io(path, error))?;
 
-        let deser: HashMap<String, SahihConfig> = serde_json::from_str(&raw_config).unwrap();
+        let deser: HashMap<String, SahihConfig> = serde_json::from_str(&raw_config)
+            .map_err(|error| SahihError::parse_json(path, error))?;
         debug!("Deser config to {:#?}", deser);
 
-        Self { projects: deser }
+        Ok(Self { projects: deser })
     }
 }
 
@@ -48,7 +96,7 @@ mod tests {
     fn it_deser_from_example_config() {
         let file_path = "./fixtures/sahih.config.json";
 
-        let config_manager = crate::config::ConfigManager::from(file_path);
+        let config_manager = crate::config::ConfigManager::from(file_path).unwrap();
         let target_output = "./assets/generated/model";
         let overwrite = false;
         let target_input = "./fixtures/api-schema.json";
@@ -59,4 +107,12 @@ mod tests {
         assert_eq!(alphaproject.input.target, target_input);
         assert_eq!(alphaproject.output.overwrite, overwrite);
     }
+
+    #[test]
+    fn it_reports_missing_config() {
+        let error =
+            crate::config::ConfigManager::from("./fixtures/missing.config.json").unwrap_err();
+
+        assert!(matches!(error, crate::error::SahihError::Io { .. }));
+    }
 }
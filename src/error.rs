@@ -0,0 +1,211 @@
+use std::{fmt, io};
+
+#[derive(Debug)]
+pub enum SahihError {
+    /// Reading an input or writing an output failed
+    Io {
+        path: Option<String>,
+        source: io::Error,
+    },
+    /// An input file isn't valid JSON or doesn't match the expected shape, `location` is the
+    /// line and column of the error when the parser knows it
+    Parse {
+        path: String,
+        location: Option<(usize, usize)>,
+        message: String,
+    },
+    /// Downloading a remote spec failed, `status` is set when the server answered with a non-2xx
+    Fetch {
+        url: String,
+        status: Option<u16>,
+        message: String,
+    },
+    /// The spec has no `components` section to generate models from
+    MissingComponents { path: String },
+    /// The spec relies on a construct sahih can't generate code for
+    Unsupported(String),
+}
+
+impl SahihError {
+    pub fn io(path: &str, source: io::Error) -> Self {
+        SahihError::Io {
+            path: Some(path.to_owned()),
+            source,
+        }
+    }
+
+    fn parse(path: &str, location: Option<(usize, usize)>, message: String) -> Self {
+        // Both serde_json and serde_yaml append the position to their messages, it's rendered separately
+        let message = match location {
+            Some((line, column)) => {
+                let position = format!(" at line {} column {}", line, column);
+                message.trim_end_matches(&position).to_owned()
+            }
+            None => message,
+        };
+
+        SahihError::Parse {
+            path: path.to_owned(),
+            location,
+            message,
+        }
+    }
+
+    pub fn parse_json(path: &str, error: serde_json::Error) -> Self {
+        // serde_json reports line 0 for errors which aren't tied to a position
+        let location = Some((error.line(), error.column())).filter(|(line, _)| *line > 0);
+        Self::parse(path, location, error.to_string())
+    }
+
+    pub fn parse_yaml(path: &str, error: serde_yaml::Error) -> Self {
+        let location = error
+            .location()
+            .map(|location| (location.line(), location.column()));
+        Self::parse(path, location, error.to_string())
+    }
+
+    /// Exit code of the `sahih` binary, distinct for every kind of error
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            SahihError::Io { .. } => 2,
+            SahihError::Parse { .. } => 3,
+            SahihError::MissingComponents { .. } => 4,
+            SahihError::Unsupported(_) => 5,
+            SahihError::Fetch { .. } => 6,
+        }
+    }
+}
+
+impl fmt::Display for SahihError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SahihError::Io {
+                path: Some(path),
+                source,
+            } => write!(f, "Could not access {}: {}", path, source),
+            SahihError::Io { path: None, source } => write!(f, "I/O error: {}", source),
+            SahihError::Parse {
+                path,
+                location: Some((line, column)),
+                message,
+            } => write!(
+                f,
+                "Could not parse {} at line {}, column {}: {}",
+                path, line, column, message
+            ),
+            SahihError::Parse {
+                path,
+                location: None,
+                message,
+            } => write!(f, "Could not parse {}: {}", path, message),
+            SahihError::MissingComponents { path } => {
+                write!(
+                    f,
+                    "{} has no `components` section to generate models from",
+                    path
+                )
+            }
+            SahihError::Unsupported(construct) => write!(f, "Unsupported construct: {}", construct),
+            SahihError::Fetch {
+                url,
+                status: Some(status),
+                message,
+            } => write!(
+                f,
+                "Could not fetch {}: server answered {} {}",
+                url, status, message
+            ),
+            SahihError::Fetch {
+                url,
+                status: None,
+                message,
+            } => write!(f, "Could not fetch {}: {}", url, message),
+        }
+    }
+}
+
+impl std::error::Error for SahihError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SahihError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for SahihError {
+    fn from(source: io::Error) -> Self {
+        SahihError::Io { path: None, source }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashSet, io};
+
+    use super::SahihError;
+
+    #[test]
+    fn it_keeps_parse_position() {
+        let json_error = serde_json::from_str::<serde_json::Value>("{\n  \"a\": }").unwrap_err();
+        let error = SahihError::parse_json("spec.json", json_error);
+
+        match &error {
+            SahihError::Parse { location, .. } => assert_eq!(*location, Some((2, 8))),
+            other => panic!("Expected a parse error, got {:?}", other),
+        }
+        assert_eq!(
+            error.to_string(),
+            "Could not parse spec.json at line 2, column 8: expected value"
+        );
+    }
+
+    #[test]
+    fn it_keeps_yaml_parse_position() {
+        let yaml_error = serde_yaml::from_str::<serde_json::Value>("a: 1\nb: [\n").unwrap_err();
+        let error = SahihError::parse_yaml("spec.yaml", yaml_error);
+
+        assert_eq!(
+            error.to_string(),
+            "Could not parse spec.yaml at line 3, column 1: while parsing a node, did not find expected node content"
+        );
+    }
+
+    #[test]
+    fn it_omits_unknown_yaml_position() {
+        let yaml_error = serde_yaml::from_str::<Vec<u8>>("[1, 2]\n---\n[3]\n").unwrap_err();
+        assert!(yaml_error.location().is_none());
+        let error = SahihError::parse_yaml("spec.yaml", yaml_error);
+
+        assert_eq!(
+            error.to_string(),
+            "Could not parse spec.yaml: deserializing from YAML containing more than one document is not supported"
+        );
+    }
+
+    #[test]
+    fn it_has_distinct_exit_codes() {
+        let errors = [
+            SahihError::io("spec.json", io::Error::from(io::ErrorKind::NotFound)),
+            SahihError::Parse {
+                path: "spec.json".to_string(),
+                location: Some((1, 1)),
+                message: String::new(),
+            },
+            SahihError::MissingComponents {
+                path: "spec.json".to_string(),
+            },
+            SahihError::Unsupported("swagger 2.0".to_string()),
+            SahihError::Fetch {
+                url: "http://localhost/api-json".to_string(),
+                status: Some(401),
+                message: "Unauthorized".to_string(),
+            },
+        ];
+
+        let codes: HashSet<i32> = errors.iter().map(SahihError::exit_code).collect();
+
+        assert_eq!(codes.len(), errors.len());
+        assert!(codes.iter().all(|code| *code > 1));
+    }
+}
@@ -4,29 +4,51 @@ extern crate serde;
 extern crate serde_json;
 
 use std::{
-    fs::OpenOptions,
-    io::{self, Write},
+    collections::HashMap,
+    fs::{File, OpenOptions},
+    io::Write,
     path::PathBuf,
 };
 
 use config::ConfigManager;
+use error::SahihError;
+use indexmap::IndexSet;
 use log::{debug, info};
 use printer::{ColorPalette, Print, PrintHelper};
 
 use crate::{
-    codegen::{InterfaceGenerator, ValidationGenerator},
-    reader::consume_schemas,
+    codegen::{
+        model::{derive_variants, Model},
+        operation::Operation,
+        ClientGenerator, HooksGenerator, InterfaceGenerator, OperationTypesGenerator,
+        ValidationGenerator, ValidatorEmitter,
+    },
+    reader::{consume_operations, consume_schemas, read_input},
 };
 
 pub mod codegen;
 pub mod config;
 pub mod constants;
+pub mod error;
 pub mod printer;
 pub mod reader;
 
+fn open_output(target: &str, file_name: &str) -> Result<File, SahihError> {
+    let mut file_path = PathBuf::from(target);
+    file_path.push(file_name);
+
+    OpenOptions::new()
+        .create(true)
+        .write(true)
+        .truncate(true)
+        .open(&file_path)
+        .map_err(|error| SahihError::io(&file_path.to_string_lossy(), error))
+}
+
 pub struct Sahih<W: Print + PrintHelper> {
     config: ConfigManager,
     printer: W,
+    validators: HashMap<String, Box<dyn ValidatorEmitter>>,
 }
 
 impl<W> Sahih<W>
@@ -34,10 +56,19 @@ where
     W: Print + PrintHelper,
 {
     pub fn new(config: ConfigManager, printer: W) -> Self {
-        Sahih { config, printer }
+        Sahih {
+            config,
+            printer,
+            validators: HashMap::new(),
+        }
     }
 
-    pub fn generate(mut self) -> io::Result<()> {
+    /// Makes `emitter` available to projects configured with `"validator": "<name>"`
+    pub fn register_validator(&mut self, name: &str, emitter: Box<dyn ValidatorEmitter>) {
+        self.validators.insert(name.to_owned(), emitter);
+    }
+
+    pub fn generate(mut self) -> Result<(), SahihError> {
         debug!("Running with options: {:#?}", self.config);
 
         self.printer.print_welcome()?;
@@ -47,37 +78,184 @@ where
             info!("Starting generation of project {}", project_name);
             debug!("W/ config :\n{:#?}", project_config);
 
+            let validator_lib = &project_config.output.validator;
+            let emitter = validator_lib.emitter(&self.validators).ok_or_else(|| {
+                SahihError::Unsupported(format!(
+                    "validator library {}, register its emitter first",
+                    validator_lib.name()
+                ))
+            })?;
+
             debug!("{}", project_config.output.target);
-            std::fs::create_dir_all(&project_config.output.target).unwrap();
+            let target = &project_config.output.target;
+            std::fs::create_dir_all(target).map_err(|error| SahihError::io(target, error))?;
 
-            let mut model_file_path = PathBuf::from(&project_config.output.target);
-            model_file_path.push("models.ts");
+            let (openapi, diagnostics) = read_input(&project_config.input)?;
+            for diagnostic in &diagnostics {
+                self.printer.print_warning(&diagnostic.to_string())?;
+            }
+            if openapi.components.is_none() {
+                return Err(SahihError::MissingComponents {
+                    path: project_config.input.target,
+                });
+            }
 
-            let mut output_file = OpenOptions::new()
-                .create(true)
-                .write(true)
-                .truncate(true)
-                .open(model_file_path)
-                .unwrap();
+            let mut output_file = open_output(target, "models.ts")?;
 
             // TODO: Header file
             output_file.write_all(
-                br#"// Generated with Sahih
-import yup from "yup";
-
-"#,
+                format!("// Generated with Sahih\n{}\n\n", emitter.import()).as_bytes(),
             )?;
-            let schemas = consume_schemas(&project_config.input.target);
+            let mut schemas = consume_schemas(&openapi);
+            let mut operations = consume_operations(&openapi, &mut schemas);
+            if project_config.output.date_time_as_date {
+                schemas.iter_mut().for_each(Model::use_date_objects);
+            }
+            if project_config.output.int64_as_bigint {
+                schemas.iter_mut().for_each(Model::use_bigints);
+            }
+
+            // Raw responses hold strings and numbers, the validators convert them to the
+            // declared `Date`s and `bigint`s
+            let output = &project_config.output;
+            let response_validator = Some(emitter).filter(|_| {
+                output.validate_responses || output.date_time_as_date || output.int64_as_bigint
+            });
+            if response_validator.is_some() && emitter.response_validation().is_none() {
+                self.printer.print_warning(&format!(
+                    "{} validators can't check responses, the client returns them unchecked",
+                    validator_lib.name()
+                ))?;
+            }
+            let validates_responses =
+                response_validator.is_some() && emitter.response_validation().is_some();
+
+            let inputs = if output.input_output_variants {
+                derive_variants(&schemas, "Input", |data| data.read_only)
+            } else {
+                Default::default()
+            };
+            // Responses are checked against the output variants, servers never send `writeOnly`
+            // properties back
+            let outputs = if output.input_output_variants || validates_responses {
+                derive_variants(&schemas, "Output", |data| data.write_only)
+            } else {
+                Default::default()
+            };
+            let output_names: IndexSet<String> = outputs.keys().cloned().collect();
 
             for model in &schemas {
-                let generator = InterfaceGenerator::from(model);
+                let mut generator = InterfaceGenerator::from(model);
+                generator.enum_style = project_config.output.enum_style;
                 let serialized = generator.build();
                 debug!("Serialized:\n {}", serialized);
                 output_file.write_all(format!("{}\n", serialized).as_bytes())?;
+                if let Some(defaults) = generator
+                    .build_defaults()
+                    .filter(|_| project_config.output.defaults)
+                {
+                    output_file.write_all(format!("{}\n", defaults).as_bytes())?;
+                }
 
-                let generator = ValidationGenerator::from(model);
-                output_file.write_all(format!("{}\n\n\n", generator.build()).as_bytes())?;
+                let mut generator = ValidationGenerator::from(model);
+                generator.enum_style = project_config.output.enum_style;
+                output_file.write_all(format!("{}\n\n\n", generator.build(emitter)).as_bytes())?;
                 info!("Serialized:\n {:#?}", generator);
+                for diagnostic in &generator.diagnostics {
+                    self.printer.print_warning(&diagnostic.to_string())?;
+                }
+
+                // Forms validate what they send, the output variants are only validated as responses
+                if let Some(input) = inputs.get(&model.name) {
+                    let mut generator = InterfaceGenerator::from(input);
+                    generator.enum_style = project_config.output.enum_style;
+                    output_file.write_all(format!("{}\n", generator.build()).as_bytes())?;
+
+                    let mut generator = ValidationGenerator::from(input);
+                    generator.enum_style = project_config.output.enum_style;
+                    output_file
+                        .write_all(format!("{}\n\n\n", generator.build(emitter)).as_bytes())?;
+                }
+                if let Some(output) = outputs.get(&model.name) {
+                    let mut generator = InterfaceGenerator::from(output);
+                    generator.enum_style = project_config.output.enum_style;
+                    output_file.write_all(format!("{}\n", generator.build()).as_bytes())?;
+
+                    if validates_responses {
+                        let mut generator = ValidationGenerator::from(output);
+                        generator.enum_style = project_config.output.enum_style;
+                        output_file.write_all(generator.build(emitter).as_bytes())?;
+                    }
+                    output_file.write_all(b"\n\n\n")?;
+                }
+            }
+
+            let client_lib = project_config.output.client;
+
+            let mut client_file = open_output(target, "client.ts")?;
+
+            if project_config.output.date_time_as_date {
+                operations.iter_mut().for_each(Operation::use_date_objects);
+            }
+            if project_config.output.int64_as_bigint {
+                operations.iter_mut().for_each(Operation::use_bigints);
+            }
+            client_file.write_all(
+                format!(
+                    "{}\n",
+                    ClientGenerator::header(
+                        &operations,
+                        client_lib,
+                        response_validator,
+                        output.int64_as_bigint,
+                        Some(&output_names),
+                    )
+                )
+                .as_bytes(),
+            )?;
+
+            for operation in &operations {
+                let mut generator = ClientGenerator::from(operation);
+                generator.lib = client_lib;
+                generator.validator = response_validator;
+                generator.bigints = output.int64_as_bigint;
+                generator.outputs = Some(&output_names);
+                client_file.write_all(format!("{}\n", generator.build()).as_bytes())?;
+            }
+            client_file.write_all(ClientGenerator::footer(client_lib).as_bytes())?;
+
+            let mut types_file = open_output(target, "operations.ts")?;
+            types_file.write_all(
+                format!("{}\n", OperationTypesGenerator::header(&operations)).as_bytes(),
+            )?;
+            for operation in &operations {
+                let generator = OperationTypesGenerator::from(operation);
+                types_file.write_all(format!("{}\n\n", generator.build()).as_bytes())?;
+            }
+
+            if let Some(hook_lib) = project_config.output.hooks {
+                let hooks: Vec<(&Operation, String)> = operations
+                    .iter()
+                    .filter_map(|operation| {
+                        let mut generator = HooksGenerator::from(operation, hook_lib);
+                        generator.client = client_lib;
+                        generator.bigints = output.int64_as_bigint;
+                        Some((operation, generator.build()?))
+                    })
+                    .collect();
+
+                // Without any hook the imports of the header would all be unused
+                if !hooks.is_empty() {
+                    let hooked: Vec<&Operation> =
+                        hooks.iter().map(|(operation, _)| *operation).collect();
+                    let mut hooks_file = open_output(target, "hooks.ts")?;
+                    hooks_file.write_all(
+                        format!("{}\n", HooksGenerator::header(&hooked, hook_lib)).as_bytes(),
+                    )?;
+                    for (_, hook) in &hooks {
+                        hooks_file.write_all(format!("{}\n", hook).as_bytes())?;
+                    }
+                }
             }
 
             ColorPalette::BoldGreen.print(&format!("🎉 {}", &project_name), &mut self.printer)?;
@@ -1,6 +1,6 @@
 use std::io::{self, Write};
 
-use crate::{config::ConfigManager, constants::VERSION};
+use crate::{config::ConfigManager, constants::VERSION, error::SahihError};
 
 pub trait Print {
     fn print(&mut self, value: &str) -> io::Result<()>;
@@ -10,6 +10,8 @@ pub trait Print {
 pub trait PrintHelper {
     fn print_welcome(&mut self) -> io::Result<()>;
     fn print_target_projects(&mut self, config: &ConfigManager) -> io::Result<()>;
+    fn print_error(&mut self, error: &SahihError) -> io::Result<()>;
+    fn print_warning(&mut self, warning: &str) -> io::Result<()>;
 
     fn print_styled(&mut self, value: &str, opts: PrintOptions) -> io::Result<()>;
     fn println_styled(&mut self, value: &str, opts: PrintOptions) -> io::Result<()>;
@@ -61,6 +63,16 @@ impl<W: Write + termcolor::WriteColor> PrintHelper for Printer<W> {
         Ok(())
     }
 
+    fn print_error(&mut self, error: &SahihError) -> io::Result<()> {
+        ColorPalette::BoldRed.print("✖ error: ", self)?;
+        self.println(&error.to_string())
+    }
+
+    fn print_warning(&mut self, warning: &str) -> io::Result<()> {
+        ColorPalette::BoldYellow.print("⚠ warning: ", self)?;
+        self.println(warning)
+    }
+
     fn print_styled(&mut self, value: &str, opts: PrintOptions) -> io::Result<()> {
         let mut color_spec = termcolor::ColorSpec::new();
         color_spec.set_fg(Some(opts.color)).set_bold(opts.is_bold);
@@ -78,10 +90,13 @@ impl<W: Write + termcolor::WriteColor> PrintHelper for Printer<W> {
     }
 }
 
-#[allow(non_snake_case)]
+#[allow(non_snake_case, clippy::items_after_test_module)]
 #[cfg(test)]
 mod tests {
-    use crate::printer::{PrintHelper, PrintOptions, Printer};
+    use crate::{
+        error::SahihError,
+        printer::{PrintHelper, PrintOptions, Printer},
+    };
 
     #[test]
     fn test_printer__println_styled__success() {
@@ -119,10 +134,41 @@ mod tests {
 
         assert_eq!(actual_yellow, expected_yellow);
     }
+
+    #[test]
+    fn test_printer__print_error__success() {
+        let mut output = termcolor::Ansi::new(vec![]);
+        let mut printer = Printer::new(&mut output);
+
+        printer
+            .print_error(&SahihError::Unsupported("swagger 2.0".to_string()))
+            .unwrap();
+
+        let actual = String::from_utf8(output.into_inner()).unwrap();
+        let expected =
+            "\u{1b}[0m\u{1b}[1m\u{1b}[31m✖ error: \u{1b}[0mUnsupported construct: swagger 2.0\n";
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_printer__print_warning__success() {
+        let mut output = termcolor::Ansi::new(vec![]);
+        let mut printer = Printer::new(&mut output);
+
+        printer.print_warning("pattern is skipped").unwrap();
+
+        let actual = String::from_utf8(output.into_inner()).unwrap();
+        let expected = "\u{1b}[0m\u{1b}[1m\u{1b}[33m⚠ warning: \u{1b}[0mpattern is skipped\n";
+
+        assert_eq!(actual, expected);
+    }
 }
 
 pub enum ColorPalette {
     BoldGreen,
+    BoldRed,
+    BoldYellow,
     Blue,
 }
 
@@ -133,6 +179,14 @@ impl ColorPalette {
                 color: termcolor::Color::Green,
                 is_bold: true,
             },
+            ColorPalette::BoldRed => PrintOptions {
+                color: termcolor::Color::Red,
+                is_bold: true,
+            },
+            ColorPalette::BoldYellow => PrintOptions {
+                color: termcolor::Color::Yellow,
+                is_bold: true,
+            },
             ColorPalette::Blue => PrintOptions {
                 color: termcolor::Color::Blue,
                 is_bold: false,
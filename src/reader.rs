@@ -1,123 +1,1394 @@
-use indexmap::IndexMap;
+use std::{path::Path, time::Duration};
+
+use indexmap::{IndexMap, IndexSet};
 use log::warn;
-use openapiv3::{ObjectType, OpenAPI, ReferenceOr, Schema, SchemaData, SchemaKind, Type};
+use openapiv3::{
+    AdditionalProperties, Discriminator, MediaType, ObjectType, OpenAPI, Parameter,
+    ParameterSchemaOrContent, ReferenceOr, Schema, SchemaData, SchemaKind, Type,
+};
+use serde::de::DeserializeOwned;
+
+use crate::{
+    codegen::{
+        model::{
+            pointer_segment, schema_pointer, ArrayProp, Discriminated, Model, ModelProperty,
+            ModelRef, PropType, SCHEMA_REF_PREFIX,
+        },
+        operation::{Operation, OperationBody, OperationParam, OperationResponse, ParamLocation},
+        pascal_case, Diagnostic,
+    },
+    config::SahihInputConfig,
+    error::SahihError,
+};
 
-use crate::codegen::model::{Model, ModelProperty};
+const PARAMETER_REF_PREFIX: &str = "#/components/parameters/";
+const REQUEST_BODY_REF_PREFIX: &str = "#/components/requestBodies/";
+const RESPONSE_REF_PREFIX: &str = "#/components/responses/";
 
 // fn parse_schema_object() {
 //     let mut fields = IndexMap::new();
 // }
 
-fn unwrap_type(schema: SchemaKind) -> Option<Type> {
-    if let SchemaKind::Type(base_schema) = schema {
-        Some(base_schema)
-    } else {
-        None
+/// Schemas declaring `properties` without `type: object` are read as objects
+fn object_kind(schema_kind: SchemaKind) -> SchemaKind {
+    match schema_kind {
+        SchemaKind::Any(any) if !any.properties.is_empty() => {
+            SchemaKind::Type(Type::Object(ObjectType {
+                properties: any.properties,
+                required: any.required,
+                additional_properties: any.additional_properties,
+                min_properties: any.min_properties,
+                max_properties: any.max_properties,
+            }))
+        }
+        schema_kind => schema_kind,
+    }
+}
+
+fn is_object_kind(schema_kind: &SchemaKind) -> bool {
+    match schema_kind {
+        SchemaKind::Type(Type::Object(_)) => true,
+        SchemaKind::Any(any) => !any.properties.is_empty(),
+        _ => false,
     }
 }
 
-fn extract_object_type(schema: SchemaKind) -> Option<ObjectType> {
-    let base_type = unwrap_type(schema).expect("Type isn't an Object");
+/// Resolves a local `#/components/schemas/...` reference against the known components
+fn resolve_reference(reference: &str, components: &IndexSet<String>) -> Option<ModelRef> {
+    let name = match reference.strip_prefix(SCHEMA_REF_PREFIX) {
+        Some(name) => name,
+        None => {
+            warn!(
+                "Only local schema references are supported, skipping {}",
+                reference
+            );
+            return None;
+        }
+    };
 
-    match base_type {
-        Type::Object(object) => Some(object),
-        _ => {
-            warn!("Primitive Types are not supported yet");
-            None
+    if !components.contains(name) {
+        warn!("Could not resolve schema reference {}", reference);
+        return None;
+    }
+
+    Some(ModelRef::new(name))
+}
+
+/// State shared while converting schemas into models
+struct ReaderContext {
+    components: IndexSet<String>,
+    /// Names of the models already read, hoisted models can't take them
+    reserved: IndexSet<String>,
+    /// Models hoisted out of inline object schemas
+    hoisted: Vec<Model>,
+}
+
+impl ReaderContext {
+    fn new(openapi: &OpenAPI) -> Self {
+        Self {
+            components: component_names(openapi),
+            reserved: IndexSet::new(),
+            hoisted: vec![],
+        }
+    }
+
+    /// Finds a model name which clashes neither with components nor with other hoisted models
+    fn hoisted_name(&self, name: &str) -> String {
+        let is_taken = |candidate: &str| {
+            self.components.contains(candidate)
+                || self.reserved.contains(candidate)
+                || self.hoisted.iter().any(|model| model.name == candidate)
+        };
+
+        let mut candidate = name.to_string();
+        let mut suffix = 1;
+        while is_taken(&candidate) {
+            suffix += 1;
+            candidate = format!("{}{}", name, suffix);
         }
+
+        candidate
+    }
+}
+
+fn extract_model(
+    name: String,
+    pointer: &str,
+    schema_data: SchemaData,
+    object: ObjectType,
+    context: &mut ReaderContext,
+) -> Model {
+    let mut model = Model::new(&name, schema_data);
+    model.pointer = pointer.to_owned();
+
+    let hint = format!("{}Value", model.name);
+    let additional_properties = extract_additional_properties(&object, &hint, pointer, context);
+    if object.properties.is_empty() && additional_properties.is_some() {
+        model.alias = additional_properties.map(|values| PropType::Map(Box::new(values)));
+        return model;
+    }
+    model.additional_properties = additional_properties;
+    model.strict = matches!(
+        object.additional_properties,
+        Some(AdditionalProperties::Any(false))
+    );
+
+    for (prop_name, prop_schema) in object.properties {
+        let hint = format!("{}{}", model.name, pascal_case(&prop_name));
+        let prop_pointer = format!("{}/properties/{}", pointer, pointer_segment(&prop_name));
+        let (schema_data, prop_type) = if let Some(item) =
+            extract_prop_type(prop_schema.unbox(), &hint, &prop_pointer, context)
+        {
+            item
+        } else {
+            continue;
+        };
+
+        let model_prop = ModelProperty {
+            required: object.required.contains(&prop_name),
+            name: prop_name,
+            data: schema_data,
+            prop_type,
+        };
+
+        model
+            .properties
+            .insert(model_prop.name.to_owned(), model_prop);
     }
+
+    model
 }
 
-fn extract_stack_type(schema: ReferenceOr<Schema>) -> Option<(SchemaData, SchemaKind)> {
-    match schema {
+/// Type of the values `additionalProperties` allows besides the declared properties
+fn extract_additional_properties(
+    object: &ObjectType,
+    hint: &str,
+    pointer: &str,
+    context: &mut ReaderContext,
+) -> Option<PropType> {
+    match object.additional_properties.as_ref()? {
+        AdditionalProperties::Any(allowed) => Some(PropType::Any).filter(|_| *allowed),
+        AdditionalProperties::Schema(schema) => {
+            let pointer = format!("{}/additionalProperties", pointer);
+            let (_, values) = extract_prop_type((**schema).clone(), hint, &pointer, context)?;
+            Some(values)
+        }
+    }
+}
+
+/// Declares an inline object as a model of its own and references it
+fn hoist_object(
+    hint: &str,
+    pointer: &str,
+    schema_data: SchemaData,
+    object: ObjectType,
+    context: &mut ReaderContext,
+) -> PropType {
+    let name = context.hoisted_name(hint);
+    let model = extract_model(name.clone(), pointer, schema_data, object, context);
+    context.hoisted.push(model);
+
+    PropType::Reference(ModelRef::new(&name))
+}
+
+fn extract_members(
+    members: Vec<ReferenceOr<Schema>>,
+    hint: &str,
+    pointer: &str,
+    context: &mut ReaderContext,
+) -> Vec<PropType> {
+    members
+        .into_iter()
+        .enumerate()
+        .filter_map(|(index, member)| {
+            let member_hint = format!("{}{}", hint, index + 1);
+            let member_pointer = format!("{}/{}", pointer, index);
+            extract_prop_type(member, &member_hint, &member_pointer, context)
+        })
+        .map(|(_, prop_type)| prop_type)
+        .collect()
+}
+
+fn compose(members: Vec<PropType>, composition: fn(Vec<PropType>) -> PropType) -> PropType {
+    match members.len() {
+        0 => PropType::Any,
+        // Single member compositions are commonly used to attach data to a reference
+        1 => members.into_iter().next().unwrap(),
+        _ => composition(members),
+    }
+}
+
+/// Maps the tags of a discriminator to their variant, explicitly or through the variant names
+fn extract_discriminated(
+    discriminator: &Discriminator,
+    one_of: &[ReferenceOr<Schema>],
+    context: &ReaderContext,
+) -> Option<PropType> {
+    let mut variants = IndexMap::new();
+
+    if discriminator.mapping.is_empty() {
+        for member in one_of {
+            let model_ref = match member {
+                ReferenceOr::Reference { reference } => {
+                    resolve_reference(reference, &context.components)?
+                }
+                ReferenceOr::Item(_) => return None,
+            };
+            variants.insert(model_ref.name.clone(), model_ref);
+        }
+    } else {
+        for (tag, target) in &discriminator.mapping {
+            let reference = if target.starts_with('#') {
+                target.clone()
+            } else {
+                format!("{}{}", SCHEMA_REF_PREFIX, target)
+            };
+            variants.insert(
+                tag.clone(),
+                resolve_reference(&reference, &context.components)?,
+            );
+        }
+    }
+
+    Some(PropType::Discriminated(Discriminated {
+        property_name: discriminator.property_name.clone(),
+        variants,
+    }))
+}
+
+/// Converts a schema into a property type, `hint` names the models hoisted out of it
+/// and `pointer` locates the schema in the spec
+fn extract_prop_type(
+    schema: ReferenceOr<Schema>,
+    hint: &str,
+    pointer: &str,
+    context: &mut ReaderContext,
+) -> Option<(SchemaData, PropType)> {
+    let (schema_data, schema_kind) = match schema {
+        ReferenceOr::Reference { reference } => {
+            let model_ref = resolve_reference(&reference, &context.components)?;
+            return Some((SchemaData::default(), PropType::Reference(model_ref)));
+        }
         ReferenceOr::Item(item) => {
             let Schema {
                 schema_data,
                 schema_kind,
             } = item;
-            Some((schema_data, schema_kind))
+            (schema_data, schema_kind)
         }
-        _ => {
-            warn!("Schema references are not suppoted yet");
-            None
+    };
+
+    let base_type = match object_kind(schema_kind) {
+        SchemaKind::Type(base_type) => base_type,
+        SchemaKind::AllOf { all_of } => {
+            let members = extract_members(
+                all_of,
+                &format!("{}Part", hint),
+                &format!("{}/allOf", pointer),
+                context,
+            );
+            return Some((schema_data, compose(members, PropType::AllOf)));
+        }
+        SchemaKind::OneOf { one_of } if schema_data.discriminator.is_some() => {
+            let discriminator = schema_data.discriminator.as_ref().unwrap();
+            match extract_discriminated(discriminator, &one_of, context) {
+                Some(union) => return Some((schema_data, union)),
+                None => {
+                    warn!(
+                        "Discriminator {} can't be mapped, falling back to a plain oneOf",
+                        discriminator.property_name
+                    );
+                    let members = extract_members(
+                        one_of,
+                        &format!("{}Variant", hint),
+                        &format!("{}/oneOf", pointer),
+                        context,
+                    );
+                    return Some((schema_data, compose(members, PropType::OneOf)));
+                }
+            }
+        }
+        SchemaKind::OneOf { one_of } => {
+            let members = extract_members(
+                one_of,
+                &format!("{}Variant", hint),
+                &format!("{}/oneOf", pointer),
+                context,
+            );
+            return Some((schema_data, compose(members, PropType::OneOf)));
+        }
+        SchemaKind::AnyOf { any_of } => {
+            let members = extract_members(
+                any_of,
+                &format!("{}Variant", hint),
+                &format!("{}/anyOf", pointer),
+                context,
+            );
+            return Some((schema_data, compose(members, PropType::AnyOf)));
+        }
+        SchemaKind::Any(_) => return Some((schema_data, PropType::Any)),
+    };
+
+    let prop_type = match base_type {
+        Type::Array(array) => {
+            let item_hint = format!("{}Item", hint);
+            let item_pointer = format!("{}/items", pointer);
+            let (_, items) =
+                extract_prop_type(array.items.unbox(), &item_hint, &item_pointer, context)?;
+
+            PropType::Array(ArrayProp {
+                items: Box::new(items),
+                min_items: array.min_items,
+                max_items: array.max_items,
+                unique_items: array.unique_items,
+            })
+        }
+        Type::Object(object) if !object.properties.is_empty() => {
+            hoist_object(hint, pointer, schema_data.clone(), object, context)
+        }
+        Type::Object(object) if object.properties.is_empty() => {
+            let value_hint = format!("{}Value", hint);
+            match extract_additional_properties(&object, &value_hint, pointer, context) {
+                Some(values) => PropType::Map(Box::new(values)),
+                None => PropType::Type(Type::Object(object)),
+            }
+        }
+        prop_type => PropType::Type(prop_type),
+    };
+
+    Some((schema_data, prop_type))
+}
+
+fn visit_model(
+    name: &str,
+    models: &IndexMap<String, Model>,
+    visiting: &mut IndexSet<String>,
+    ordered: &mut IndexSet<String>,
+) {
+    if ordered.contains(name) || !visiting.insert(name.to_owned()) {
+        return;
+    }
+
+    // Components which produced no model are left out, their references stay unresolved
+    let model = match models.get(name) {
+        Some(model) => model,
+        None => return,
+    };
+    for model_ref in model.references() {
+        visit_model(&model_ref.name, models, visiting, ordered);
+    }
+
+    visiting.remove(name);
+    ordered.insert(name.to_owned());
+}
+
+/// Orders models so that every model is declared after the ones it references.
+/// References which can't satisfy that order (cycles) are flagged as recursive.
+fn sort_models(models: Vec<Model>) -> Vec<Model> {
+    let mut models: IndexMap<String, Model> = models
+        .into_iter()
+        .map(|model| (model.name.clone(), model))
+        .collect();
+
+    let mut visiting = IndexSet::new();
+    let mut ordered = IndexSet::new();
+    for name in models.keys() {
+        visit_model(name, &models, &mut visiting, &mut ordered);
+    }
+
+    for (index, name) in ordered.iter().enumerate() {
+        let model = models.get_mut(name).unwrap();
+        for model_ref in model.references_mut() {
+            model_ref.recursive = match ordered.get_index_of(&model_ref.name) {
+                Some(target) => target >= index,
+                None => false,
+            };
         }
     }
+
+    ordered
+        .iter()
+        .filter_map(|name| models.swap_remove(name))
+        .collect()
 }
 
-fn extract_heap_type(schema: ReferenceOr<Box<Schema>>) -> Option<(SchemaData, SchemaKind)> {
-    match schema {
-        ReferenceOr::Item(heap_item) => {
-            let item = *heap_item;
-            let Schema {
-                schema_data,
-                schema_kind,
-            } = item;
-            Some((schema_data, schema_kind))
+fn component_names(openapi: &OpenAPI) -> IndexSet<String> {
+    match &openapi.components {
+        Some(components) => components.schemas.keys().cloned().collect(),
+        None => IndexSet::new(),
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum SpecFormat {
+    Json,
+    Yaml,
+}
+
+/// Picks the format from the extension, falling back to sniffing the content
+fn detect_format(path: &str, buffer: &str) -> SpecFormat {
+    // URLs may carry a query string or fragment after the extension
+    let path = path.split(['?', '#']).next().unwrap_or(path);
+    let extension = Path::new(path)
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .map(|extension| extension.to_ascii_lowercase());
+
+    match extension.as_deref() {
+        Some("json") => SpecFormat::Json,
+        Some("yaml") | Some("yml") => SpecFormat::Yaml,
+        // YAML is a superset of JSON, only documents starting as an object are sure to be JSON
+        _ if buffer.trim_start().starts_with('{') => SpecFormat::Json,
+        _ => SpecFormat::Yaml,
+    }
+}
+
+fn parse_spec<T: DeserializeOwned>(
+    path: &str,
+    buffer: &str,
+    format: &SpecFormat,
+) -> Result<T, SahihError> {
+    match format {
+        SpecFormat::Json => {
+            serde_json::from_str(buffer).map_err(|error| SahihError::parse_json(path, error))
         }
-        _ => {
-            warn!("Schema references are not suppoted yet");
-            None
+        SpecFormat::Yaml => {
+            serde_yaml::from_str(buffer).map_err(|error| SahihError::parse_yaml(path, error))
+        }
+    }
+}
+
+fn load_spec(path: &str, schema_buffer: &str) -> Result<OpenAPI, SahihError> {
+    let format = detect_format(path, schema_buffer);
+
+    let openapi: OpenAPI = match parse_spec(path, schema_buffer, &format) {
+        Ok(openapi) => openapi,
+        Err(error) => {
+            // Swagger 2.0 specs fail on the missing `openapi` field, point at the actual cause
+            let raw: Option<serde_json::Value> = parse_spec(path, schema_buffer, &format).ok();
+            if let Some(version) = raw.as_ref().and_then(|raw| raw.get("swagger")) {
+                return Err(SahihError::Unsupported(format!(
+                    "Swagger {} spec {}, only OpenAPI 3 specs are supported",
+                    version, path
+                )));
+            }
+            return Err(error);
         }
+    };
+
+    if !openapi.openapi.starts_with("3.") {
+        return Err(SahihError::Unsupported(format!(
+            "OpenAPI {} spec {}, only OpenAPI 3 specs are supported",
+            openapi.openapi, path
+        )));
     }
+
+    Ok(openapi)
+}
+
+pub fn read_spec(path: &str) -> Result<OpenAPI, SahihError> {
+    let schema_buffer =
+        std::fs::read_to_string(path).map_err(|error| SahihError::io(path, error))?;
+
+    load_spec(path, &schema_buffer)
+}
+
+/// Downloads the spec served at `input.target`, e.g. a backend's `/api-json` endpoint
+pub fn fetch_spec(input: &SahihInputConfig) -> Result<OpenAPI, SahihError> {
+    load_spec(&input.target, &fetch_buffer(input)?)
 }
 
-pub fn consume_schemas(path: &str) -> Vec<Model> {
-    let schema_buffer = std::fs::read_to_string(path).expect("Could not find user");
-    let openapi: OpenAPI = serde_json::from_str(schema_buffer.as_str()).unwrap();
+fn fetch_buffer(input: &SahihInputConfig) -> Result<String, SahihError> {
+    let url = &input.target;
+    let agent = ureq::AgentBuilder::new()
+        .timeout(Duration::from_secs(input.timeout))
+        .build();
+
+    let mut request = agent.get(url);
+    for (name, value) in &input.headers {
+        request = request.set(name, value);
+    }
 
-    let components = openapi.components.unwrap();
+    let fetch_error = |status: Option<u16>, message: String| SahihError::Fetch {
+        url: url.to_owned(),
+        status,
+        message,
+    };
+    let response = request.call().map_err(|error| match error {
+        ureq::Error::Status(status, response) => {
+            fetch_error(Some(status), response.status_text().to_owned())
+        }
+        ureq::Error::Transport(transport) => fetch_error(None, transport.to_string()),
+    })?;
+    response
+        .into_string()
+        .map_err(|error| fetch_error(None, error.to_string()))
+}
+
+/// Reports the `not` keywords of a raw spec, openapiv3 drops them so their schemas accept anything.
+/// `names` is set on maps keyed by property or component names rather than keywords.
+fn find_negations(
+    raw: &serde_json::Value,
+    pointer: &str,
+    names: bool,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    match raw {
+        serde_json::Value::Object(object) => {
+            if !names && object.contains_key("not") {
+                diagnostics.push(Diagnostic {
+                    pointer: pointer.to_owned(),
+                    message: String::from("`not` is skipped, the schema accepts any value"),
+                });
+            }
+            for (key, value) in object {
+                // Examples are values, not schemas
+                if !names && (key == "example" || key == "examples") {
+                    continue;
+                }
+                let names = !names && (key == "properties" || key == "schemas");
+                let pointer = format!("{}/{}", pointer, pointer_segment(key));
+                find_negations(value, &pointer, names, diagnostics);
+            }
+        }
+        serde_json::Value::Array(items) => {
+            for (index, item) in items.iter().enumerate() {
+                find_negations(item, &format!("{}/{}", pointer, index), false, diagnostics);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Reads the spec of a project, from the filesystem or over HTTP depending on `from_url`,
+/// along with the constraints of the spec which are lost while reading it
+pub fn read_input(input: &SahihInputConfig) -> Result<(OpenAPI, Vec<Diagnostic>), SahihError> {
+    let path = &input.target;
+    let schema_buffer = if input.from_url {
+        fetch_buffer(input)?
+    } else {
+        std::fs::read_to_string(path).map_err(|error| SahihError::io(path, error))?
+    };
+    let openapi = load_spec(path, &schema_buffer)?;
+
+    let mut diagnostics = vec![];
+    let format = detect_format(path, &schema_buffer);
+    if let Ok(raw) = parse_spec::<serde_json::Value>(path, &schema_buffer, &format) {
+        find_negations(&raw, "#", false, &mut diagnostics);
+    }
+
+    Ok((openapi, diagnostics))
+}
+
+/// Flattens an `allOf` made of references and inline objects into a model extending the references
+fn extract_inherited_model(
+    name: &str,
+    pointer: &str,
+    schema_data: SchemaData,
+    all_of: Vec<ReferenceOr<Schema>>,
+    context: &mut ReaderContext,
+) -> Model {
+    let mut model = Model::new(name, schema_data);
+
+    for (index, member) in all_of.into_iter().enumerate() {
+        match member {
+            ReferenceOr::Reference { reference } => {
+                if let Some(model_ref) = resolve_reference(&reference, &context.components) {
+                    model.extends.push(model_ref);
+                }
+            }
+            ReferenceOr::Item(member) => {
+                if let SchemaKind::Type(Type::Object(object)) = object_kind(member.schema_kind) {
+                    let part_pointer = format!("{}/allOf/{}", pointer, index);
+                    let part = extract_model(
+                        name.to_string(),
+                        &part_pointer,
+                        member.schema_data,
+                        object,
+                        context,
+                    );
+                    model.properties.extend(part.properties);
+                }
+            }
+        }
+    }
+
+    model
+}
+
+fn is_inheritance(all_of: &[ReferenceOr<Schema>]) -> bool {
+    all_of.iter().all(|member| match member {
+        ReferenceOr::Reference { .. } => true,
+        ReferenceOr::Item(member) => is_object_kind(&member.schema_kind),
+    })
+}
+
+/// Declares a component schema as a named alias of the type it describes
+fn extract_alias(
+    name: &str,
+    schema_data: SchemaData,
+    schema: ReferenceOr<Schema>,
+    context: &mut ReaderContext,
+) -> Option<Model> {
+    let pointer = schema_pointer(name);
+    let (_, alias) = extract_prop_type(schema, name, &pointer, context)?;
+
+    let mut model = Model::new(name, schema_data);
+    model.alias = Some(alias);
+    Some(model)
+}
+
+pub fn consume_schemas(openapi: &OpenAPI) -> Vec<Model> {
+    let components = openapi.components.clone().unwrap_or_default();
     let schemas = components.schemas;
+    let mut context = ReaderContext::new(openapi);
 
     let mut unwrapped_schemas: Vec<Model> = vec![];
 
     for schematype in schemas {
         let (model_name, model_data) = schematype;
 
-        let (schema_data, schema_kind) = if let Some(item) = extract_stack_type(model_data) {
-            item
-        } else {
-            continue;
+        let pointer = schema_pointer(&model_name);
+        let (schema_data, schema_kind) = match model_data {
+            ReferenceOr::Item(Schema {
+                schema_data,
+                schema_kind,
+            }) => (schema_data, schema_kind),
+            // A component which is only a `$ref` is an alias of the referenced schema
+            reference => {
+                let alias =
+                    extract_alias(&model_name, SchemaData::default(), reference, &mut context);
+                unwrapped_schemas.extend(alias);
+                continue;
+            }
         };
 
-        let schema_object = if let Some(object) = extract_object_type(schema_kind) {
-            object
-        } else {
-            continue;
+        let model = match object_kind(schema_kind) {
+            SchemaKind::Type(Type::Object(schema_object)) => extract_model(
+                model_name,
+                &pointer,
+                schema_data,
+                schema_object,
+                &mut context,
+            ),
+            SchemaKind::AllOf { all_of } if is_inheritance(&all_of) => {
+                extract_inherited_model(&model_name, &pointer, schema_data, all_of, &mut context)
+            }
+            // Enumerations, primitives and compositions are named aliases
+            schema_kind => {
+                let schema = ReferenceOr::Item(Schema {
+                    schema_data: schema_data.clone(),
+                    schema_kind,
+                });
+                match extract_alias(&model_name, schema_data, schema, &mut context) {
+                    Some(model) => model,
+                    None => continue,
+                }
+            }
         };
 
-        let mut model = Model {
-            name: model_name,
-            data: schema_data,
-            properties: IndexMap::new(),
-        };
+        unwrapped_schemas.push(model);
+    }
 
-        for (prop_name, prop_schema) in schema_object.properties {
-            let (schema_data, schema_kind) = if let Some(item) = extract_heap_type(prop_schema) {
-                item
-            } else {
+    unwrapped_schemas.extend(context.hoisted);
+
+    sort_models(unwrapped_schemas)
+}
+
+/// Resolves a local reference to a non-schema component (parameters, bodies, responses)
+fn resolve_component<'a, T>(
+    item: &'a ReferenceOr<T>,
+    prefix: &str,
+    components: &'a IndexMap<String, ReferenceOr<T>>,
+) -> Option<&'a T> {
+    let reference = match item {
+        ReferenceOr::Item(item) => return Some(item),
+        ReferenceOr::Reference { reference } => reference,
+    };
+
+    match reference
+        .strip_prefix(prefix)
+        .and_then(|name| components.get(name))
+    {
+        Some(ReferenceOr::Item(item)) => Some(item),
+        _ => {
+            warn!("Could not resolve component reference {}", reference);
+            None
+        }
+    }
+}
+
+fn json_schema(content: &IndexMap<String, MediaType>) -> Option<&ReferenceOr<Schema>> {
+    let media_type = content
+        .get("application/json")
+        .or_else(|| {
+            content
+                .iter()
+                .find(|(mime, _)| mime.contains("json"))
+                .map(|(_, media_type)| media_type)
+        })
+        .or_else(|| content.values().next())?;
+
+    media_type.schema.as_ref()
+}
+
+fn operation_name(method: &str, path: &str, operation: &openapiv3::Operation) -> String {
+    let raw_name = match &operation.operation_id {
+        Some(operation_id) => operation_id.clone(),
+        None => format!("{}_{}", method, path),
+    };
+
+    let name = raw_name
+        .split(|c: char| !c.is_ascii_alphanumeric() && c != '$')
+        .filter(|chunk| !chunk.is_empty())
+        .collect::<Vec<&str>>()
+        .join("_");
+
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{}", name)
+    } else {
+        name
+    }
+}
+
+fn extract_param(
+    param: &Parameter,
+    hint: &str,
+    pointer: &str,
+    context: &mut ReaderContext,
+) -> Option<OperationParam> {
+    let (parameter_data, location) = match param {
+        Parameter::Path { parameter_data, .. } => (parameter_data, ParamLocation::Path),
+        Parameter::Query { parameter_data, .. } => (parameter_data, ParamLocation::Query),
+        Parameter::Header { parameter_data, .. } => (parameter_data, ParamLocation::Header),
+        Parameter::Cookie { parameter_data, .. } => (parameter_data, ParamLocation::Cookie),
+    };
+
+    let schema = match &parameter_data.format {
+        ParameterSchemaOrContent::Schema(schema) => schema,
+        ParameterSchemaOrContent::Content(content) => json_schema(content)?,
+    };
+
+    let hint = format!("{}{}", hint, pascal_case(&parameter_data.name));
+    let (_, prop_type) = extract_prop_type(schema.clone(), &hint, pointer, context)?;
+
+    Some(OperationParam {
+        name: parameter_data.name.clone(),
+        location,
+        // Path parameters are always required
+        required: parameter_data.required || location == ParamLocation::Path,
+        prop_type,
+    })
+}
+
+/// Reads the operations of the spec, inline objects of their params, bodies and responses
+/// are hoisted into models added to `models`
+pub fn consume_operations(openapi: &OpenAPI, models: &mut Vec<Model>) -> Vec<Operation> {
+    let components = openapi.components.clone().unwrap_or_default();
+    let mut context = ReaderContext::new(openapi);
+    context.reserved = models.iter().map(|model| model.name.clone()).collect();
+
+    let mut operations: Vec<Operation> = vec![];
+
+    for (path, path_item) in &openapi.paths {
+        let path_item = match path_item {
+            ReferenceOr::Item(path_item) => path_item,
+            _ => {
+                warn!(
+                    "Path item references are not supported yet, skipping {}",
+                    path
+                );
                 continue;
+            }
+        };
+
+        let methods = [
+            ("get", &path_item.get),
+            ("put", &path_item.put),
+            ("post", &path_item.post),
+            ("delete", &path_item.delete),
+            ("options", &path_item.options),
+            ("head", &path_item.head),
+            ("patch", &path_item.patch),
+            ("trace", &path_item.trace),
+        ];
+
+        for (method, operation) in methods {
+            let operation = match operation {
+                Some(operation) => operation,
+                None => continue,
             };
 
-            let prop_type = match unwrap_type(schema_kind) {
-                Some(prop_type) => prop_type,
-                None => {
-                    continue;
+            let pointer = format!("#/paths/{}/{}", pointer_segment(path), method);
+            let name = operation_name(method, path, operation);
+            let type_name = pascal_case(&name);
+
+            // Operation level parameters override the path level ones of the same name and location
+            let mut parameters: IndexMap<(String, ParamLocation), OperationParam> = IndexMap::new();
+            for param in path_item.parameters.iter().chain(&operation.parameters) {
+                let param = resolve_component(param, PARAMETER_REF_PREFIX, &components.parameters)
+                    .and_then(|param| {
+                        let params_pointer = format!("{}/parameters", pointer);
+                        extract_param(param, &type_name, &params_pointer, &mut context)
+                    });
+
+                if let Some(param) = param {
+                    parameters.insert((param.name.clone(), param.location), param);
                 }
-            };
+            }
+
+            let body = operation
+                .request_body
+                .as_ref()
+                .and_then(|body| {
+                    resolve_component(body, REQUEST_BODY_REF_PREFIX, &components.request_bodies)
+                })
+                .and_then(|body| {
+                    let schema = json_schema(&body.content)?;
+                    let body_pointer = format!("{}/requestBody", pointer);
+                    let hint = format!("{}Request", type_name);
+                    let (_, prop_type) =
+                        extract_prop_type(schema.clone(), &hint, &body_pointer, &mut context)?;
+                    Some(OperationBody {
+                        required: body.required,
+                        prop_type,
+                    })
+                });
 
-            let model_prop = ModelProperty {
-                name: prop_name,
-                data: schema_data,
-                prop_type,
+            let statuses = operation
+                .responses
+                .responses
+                .iter()
+                .map(|(status, response)| (status.to_string(), response))
+                .chain(
+                    (operation.responses.default.iter())
+                        .map(|response| (String::from("default"), response)),
+                );
+            let mut responses: Vec<OperationResponse> = vec![];
+            for (status, response) in statuses {
+                let response =
+                    match resolve_component(response, RESPONSE_REF_PREFIX, &components.responses) {
+                        Some(response) => response,
+                        None => continue,
+                    };
+                let response_pointer = format!("{}/responses/{}", pointer, status);
+                let prop_type = match json_schema(&response.content) {
+                    Some(schema) => extract_prop_type(
+                        schema.clone(),
+                        &format!("{}{}Response", type_name, pascal_case(&status)),
+                        &response_pointer,
+                        &mut context,
+                    )
+                    .map(|(_, prop_type)| prop_type),
+                    // Content without a schema can be anything
+                    None if !response.content.is_empty() => Some(PropType::Any),
+                    None => None,
+                };
+
+                responses.push(OperationResponse { status, prop_type });
+            }
+            let response = responses
+                .iter()
+                .filter(|response| response.is_success())
+                .find_map(|response| response.prop_type.clone());
+
+            operations.push(Operation {
+                name,
+                method: method.to_string(),
+                path: path.clone(),
+                parameters: parameters.into_iter().map(|(_, param)| param).collect(),
+                body,
+                response,
+                responses,
+            });
+        }
+    }
+
+    models.extend(context.hoisted);
+
+    operations
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::codegen::{
+        model::{Model, ModelRef, PropType},
+        operation::ParamLocation,
+        Diagnostic,
+    };
+
+    use std::{
+        collections::HashMap,
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        thread,
+    };
+
+    use openapiv3::Type;
+
+    use crate::{config::SahihInputConfig, error::SahihError};
+
+    use super::{
+        consume_operations, consume_schemas, detect_format, fetch_spec, read_input, read_spec,
+        SpecFormat,
+    };
+
+    /// Serves a single request, answering 401 unless it carries the expected `Authorization` header
+    fn serve_spec(body: &'static str) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/api-json", listener.local_addr().unwrap());
+
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut authorized = false;
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut line = String::new();
+            while reader.read_line(&mut line).unwrap() > 0 && line != "\r\n" {
+                authorized |= line.eq_ignore_ascii_case("authorization: bearer token\r\n");
+                line.clear();
+            }
+
+            let (status, body) = if authorized {
+                ("200 OK", body)
+            } else {
+                ("401 Unauthorized", "")
             };
+            write!(
+                stream,
+                "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            )
+            .unwrap();
+        });
+
+        url
+    }
+
+    fn remote_input(url: String, token: Option<&str>) -> SahihInputConfig {
+        let mut headers = HashMap::new();
+        if let Some(token) = token {
+            headers.insert("Authorization".to_string(), format!("Bearer {}", token));
+        }
 
-            model
+        SahihInputConfig {
+            target: url,
+            from_url: true,
+            headers,
+            timeout: 5,
+        }
+    }
+
+    fn find_ref<'a>(models: &'a [Model], model: &str, prop: &str) -> &'a ModelRef {
+        let model = models.iter().find(|m| m.name == model).unwrap();
+        match &model.properties.get(prop).unwrap().prop_type {
+            PropType::Reference(model_ref) => model_ref,
+            other => panic!("Expected a reference, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn it_resolves_component_references() {
+        let models = consume_schemas(&read_spec("./fixtures/api-schema.json").unwrap());
+
+        let bidder = find_ref(&models, "BidDto", "bidder");
+        assert_eq!(bidder, &ModelRef::new("BidderDto"));
+
+        let position = |name: &str| models.iter().position(|m| m.name == name).unwrap();
+        assert!(position("BidderDto") < position("BidDto"));
+    }
+
+    #[test]
+    fn it_consumes_operations() {
+        let operations = consume_operations(
+            &read_spec("./fixtures/api-schema.json").unwrap(),
+            &mut vec![],
+        );
+        let create = operations
+            .iter()
+            .find(|op| op.name == "AuctionController_createAuction")
+            .unwrap();
+
+        assert_eq!(operations.len(), 8);
+        assert_eq!(create.method, "post");
+        assert_eq!(create.path, "/auction");
+        assert_eq!(
+            create.references(),
+            vec!["AuctionCreateInput", "AuctionRoomDTO"]
+        );
+
+        let get = operations
+            .iter()
+            .find(|op| op.name == "AuctionController_getAuction")
+            .unwrap();
+        let house_id = &get.parameters[0];
+
+        assert_eq!(house_id.name, "houseId");
+        assert_eq!(house_id.location, ParamLocation::Query);
+        assert!(house_id.required);
+
+        let statuses: Vec<&str> = create
+            .responses
+            .iter()
+            .map(|response| response.status.as_str())
+            .collect();
+        assert_eq!(statuses, vec!["200"]);
+        assert!(create.responses[0].is_success());
+    }
+
+    #[test]
+    fn it_hoists_inline_array_items() {
+        let models = consume_schemas(&read_spec("./fixtures/nested-schema.json").unwrap());
+        let gallery = models.iter().find(|m| m.name == "Gallery").unwrap();
+        match &gallery.properties.get("tags").unwrap().prop_type {
+            PropType::Array(array) => {
+                assert_eq!(array.min_items, Some(1));
+                assert!(array.unique_items);
+            }
+            other => panic!("Expected an array, got {:?}", other),
+        }
+
+        match &gallery.properties.get("frames").unwrap().prop_type {
+            PropType::Array(array) => assert_eq!(
+                array.items.references(),
+                vec![&ModelRef::new("GalleryFramesItem")]
+            ),
+            other => panic!("Expected an array, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn it_locates_hoisted_models() {
+        let models = consume_schemas(&read_spec("./fixtures/nested-schema.json").unwrap());
+        let pointers: Vec<&str> = models.iter().map(|m| m.pointer.as_str()).collect();
+        assert_eq!(
+            pointers,
+            vec![
+                "#/components/schemas/Gallery/properties/frames/items",
+                "#/components/schemas/Gallery/properties/settings/properties/theme",
+                "#/components/schemas/Gallery/properties/settings",
+                "#/components/schemas/Gallery"
+            ]
+        );
+    }
+
+    #[test]
+    fn it_hoists_nested_inline_objects() {
+        let models = consume_schemas(&read_spec("./fixtures/nested-schema.json").unwrap());
+        let names: Vec<&str> = models.iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "GalleryFramesItem",
+                "GallerySettingsTheme",
+                "GallerySettings",
+                "Gallery"
+            ]
+        );
+
+        let settings = find_ref(&models, "Gallery", "settings");
+        assert_eq!(settings, &ModelRef::new("GallerySettings"));
+
+        let theme = find_ref(&models, "GallerySettings", "theme");
+        assert_eq!(theme, &ModelRef::new("GallerySettingsTheme"));
+
+        let theme_model = models.iter().find(|m| m.name == "GallerySettingsTheme");
+        assert!(
+            theme_model
+                .unwrap()
                 .properties
-                .insert(model_prop.name.to_owned(), model_prop);
+                .get("accent")
+                .unwrap()
+                .required
+        );
+    }
+
+    #[test]
+    fn it_hoists_inline_operation_objects() {
+        let openapi = read_spec("./fixtures/nested-schema.json").unwrap();
+        let mut models = consume_schemas(&openapi);
+        let operations = consume_operations(&openapi, &mut models);
+
+        assert_eq!(
+            operations[0].references(),
+            vec!["CreateItemRequest", "CreateItem201Response"]
+        );
+
+        let body = models
+            .iter()
+            .find(|m| m.name == "CreateItemRequest")
+            .unwrap();
+        assert_eq!(body.pointer, "#/paths/~1items/post/requestBody");
+        assert!(body.properties["name"].required);
+        assert!(models.iter().any(|m| m.name == "CreateItem201Response"));
+    }
+
+    #[test]
+    fn it_flattens_inheritance() {
+        let models = consume_schemas(&read_spec("./fixtures/composition-schema.json").unwrap());
+        let position = |name: &str| models.iter().position(|m| m.name == name).unwrap();
+        let dog = &models[position("Dog")];
+
+        assert!(position("Pet") < position("Dog"));
+        assert_eq!(dog.extends, vec![ModelRef::new("Pet")]);
+        assert!(dog.properties.get("barks").unwrap().required);
+        assert!(dog.alias.is_none());
+    }
+
+    #[test]
+    fn it_reads_compositions() {
+        let models = consume_schemas(&read_spec("./fixtures/composition-schema.json").unwrap());
+        let animal = models.iter().find(|m| m.name == "Animal").unwrap();
+
+        match &animal.alias {
+            Some(PropType::OneOf(members)) => assert_eq!(members.len(), 2),
+            other => panic!("Expected a oneOf alias, got {:?}", other),
         }
 
-        unwrapped_schemas.push(model);
+        let favorite = find_ref(&models, "Owner", "favorite");
+        assert_eq!(favorite, &ModelRef::new("Dog"));
+
+        let owner = models.iter().find(|m| m.name == "Owner").unwrap();
+        let prop_type = |name: &str| &owner.properties.get(name).unwrap().prop_type;
+
+        assert!(matches!(prop_type("nemesis"), PropType::Any));
+        match prop_type("contact") {
+            PropType::OneOf(members) => assert_eq!(
+                members[1].references(),
+                vec![&ModelRef::new("OwnerContactVariant2")]
+            ),
+            other => panic!("Expected a oneOf, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn it_reads_primitive_schemas() {
+        let models = consume_schemas(&read_spec("./fixtures/enum-schema.json").unwrap());
+        let model = |name: &str| models.iter().find(|m| m.name == name).unwrap();
+
+        assert_eq!(
+            model("AuctionStatus").enumeration(),
+            Some(vec![
+                serde_json::json!("open"),
+                serde_json::json!("in-progress"),
+                serde_json::json!("closed")
+            ])
+        );
+        assert!(matches!(
+            model("Sku").alias,
+            Some(PropType::Type(Type::String(_)))
+        ));
+        assert_eq!(model("Sku").enumeration(), None);
+
+        let status = find_ref(&models, "Auction", "status");
+        assert_eq!(status, &ModelRef::new("AuctionStatus"));
+    }
+
+    #[test]
+    fn it_reads_additional_properties() {
+        let models = consume_schemas(&read_spec("./fixtures/map-schema.json").unwrap());
+        let model = |name: &str| models.iter().find(|m| m.name == name).unwrap();
+
+        assert!(matches!(
+            &model("Labels").alias,
+            Some(PropType::Map(values)) if matches!(**values, PropType::Type(Type::String(_)))
+        ));
+
+        let settings = model("Settings");
+        assert!(matches!(
+            settings.additional_properties,
+            Some(PropType::Type(Type::Integer(_)))
+        ));
+        match &settings.properties.get("metadata").unwrap().prop_type {
+            PropType::Map(values) => assert_eq!(
+                values.references(),
+                vec![&ModelRef::new("SettingsMetadataValue")]
+            ),
+            other => panic!("Expected a map, got {:?}", other),
+        }
+
+        assert!(model("Strict").strict);
+        assert!(matches!(
+            model("Loose").additional_properties,
+            Some(PropType::Any)
+        ));
+    }
+
+    #[test]
+    fn it_reads_read_write_flags() {
+        let models = consume_schemas(&read_spec("./fixtures/read-write-schema.json").unwrap());
+        let user = models.iter().find(|m| m.name == "User").unwrap();
+
+        assert!(user.properties["id"].data.read_only);
+        assert!(user.properties["password"].data.write_only);
+        assert!(!user.properties["email"].data.read_only);
+    }
+
+    #[test]
+    fn it_reads_discriminators() {
+        let models = consume_schemas(&read_spec("./fixtures/composition-schema.json").unwrap());
+        let union = |name: &str| match &models.iter().find(|m| m.name == name).unwrap().alias {
+            Some(PropType::Discriminated(union)) => union,
+            other => panic!("Expected a discriminated union, got {:?}", other),
+        };
+
+        let event = union("AuctionEvent");
+        assert_eq!(event.property_name, "type");
+        assert_eq!(
+            event.variants.get("bid_placed"),
+            Some(&ModelRef::new("BidPlaced"))
+        );
+        assert_eq!(
+            event.variants.get("ended"),
+            Some(&ModelRef::new("AuctionEnded"))
+        );
+
+        let creature = union("Creature");
+        let tags: Vec<&String> = creature.variants.keys().collect();
+        assert_eq!(tags, vec!["Cat", "Dog"]);
     }
 
-    unwrapped_schemas
+    #[test]
+    fn it_detects_reference_cycles() {
+        let models = consume_schemas(&read_spec("./fixtures/recursive-schema.json").unwrap());
+        let names: Vec<&str> = models.iter().map(|m| m.name.as_str()).collect();
+
+        assert_eq!(names, vec!["Publisher", "Book", "Author"]);
+        assert!(find_ref(&models, "Publisher", "parent").recursive);
+        assert!(find_ref(&models, "Book", "author").recursive);
+        assert!(!find_ref(&models, "Book", "publisher").recursive);
+        assert!(!find_ref(&models, "Author", "latestBook").recursive);
+    }
+
+    #[test]
+    fn it_carries_required_props() {
+        let models = consume_schemas(&read_spec("./fixtures/recursive-schema.json").unwrap());
+        let publisher = models.iter().find(|m| m.name == "Publisher").unwrap();
+
+        assert!(publisher.properties.get("name").unwrap().required);
+        assert!(!publisher.properties.get("parent").unwrap().required);
+    }
+
+    #[test]
+    fn it_skips_unresolvable_references() {
+        let models = consume_schemas(&read_spec("./fixtures/recursive-schema.json").unwrap());
+        let publisher = models.iter().find(|m| m.name == "Publisher").unwrap();
+
+        assert!(!publisher.properties.contains_key("website"));
+    }
+
+    #[test]
+    fn it_reads_component_references_as_aliases() {
+        let models = consume_schemas(&read_spec("./fixtures/alias-ref-schema.json").unwrap());
+        let names: Vec<&str> = models.iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(names, vec!["Thing", "Alias", "Holder"]);
+
+        match &models[1].alias {
+            Some(PropType::Reference(model_ref)) => assert_eq!(model_ref, &ModelRef::new("Thing")),
+            other => panic!("Expected a reference, got {:?}", other),
+        }
+        assert_eq!(find_ref(&models, "Holder", "a"), &ModelRef::new("Alias"));
+    }
+
+    #[test]
+    fn it_reads_untyped_objects() {
+        let models = consume_schemas(&read_spec("./fixtures/untyped-schema.json").unwrap());
+        let address = &models[0];
+
+        assert!(address.alias.is_none());
+        assert!(address.properties["city"].required);
+        assert!(matches!(address.properties["zip"].prop_type, PropType::Any));
+    }
+
+    #[test]
+    fn it_reports_negations() {
+        let input = SahihInputConfig {
+            target: "./fixtures/untyped-schema.json".to_string(),
+            from_url: false,
+            headers: HashMap::new(),
+            timeout: 5,
+        };
+        let (_, diagnostics) = read_input(&input).unwrap();
+
+        assert_eq!(
+            diagnostics,
+            [Diagnostic {
+                pointer: "#/components/schemas/Address/properties/zip".to_string(),
+                message: "`not` is skipped, the schema accepts any value".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn it_reports_missing_spec() {
+        match read_spec("./fixtures/missing-schema.json") {
+            Err(SahihError::Io { path, .. }) => {
+                assert_eq!(path.as_deref(), Some("./fixtures/missing-schema.json"))
+            }
+            other => panic!("Expected an I/O error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn it_reports_malformed_spec_position() {
+        match read_spec("./fixtures/malformed-schema.json") {
+            Err(SahihError::Parse { location, .. }) => assert_eq!(location, Some((5, 3))),
+            other => panic!("Expected a parse error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn it_rejects_swagger_specs() {
+        assert!(matches!(
+            read_spec("./fixtures/swagger-schema.json"),
+            Err(SahihError::Unsupported(_))
+        ));
+    }
+
+    #[test]
+    fn it_detects_spec_format() {
+        assert_eq!(
+            detect_format("api.json", "openapi: 3.0.0"),
+            SpecFormat::Json
+        );
+        assert_eq!(detect_format("api.YML", "{}"), SpecFormat::Yaml);
+        assert_eq!(
+            detect_format("api-json", "  {\"openapi\": \"3.0.0\"}"),
+            SpecFormat::Json
+        );
+        assert_eq!(
+            detect_format("api-json", "openapi: 3.0.0"),
+            SpecFormat::Yaml
+        );
+    }
+
+    #[test]
+    fn it_reads_yaml_specs() {
+        let from_yaml = read_spec("./fixtures/api-schema.yaml").unwrap();
+        let from_json = read_spec("./fixtures/api-schema.json").unwrap();
+
+        assert_eq!(from_yaml, from_json);
+    }
+
+    #[test]
+    fn it_fetches_remote_specs() {
+        let spec = include_str!("../fixtures/api-schema.json");
+        let input = remote_input(serve_spec(spec), Some("token"));
+
+        assert_eq!(
+            fetch_spec(&input).unwrap(),
+            read_spec("./fixtures/api-schema.json").unwrap()
+        );
+    }
+
+    #[test]
+    fn it_reports_failed_fetches() {
+        let input = remote_input(serve_spec("{}"), None);
+
+        match fetch_spec(&input) {
+            Err(SahihError::Fetch { status, .. }) => assert_eq!(status, Some(401)),
+            other => panic!("Expected a fetch error, got {:?}", other),
+        }
+    }
 }